@@ -0,0 +1,151 @@
+use {
+    crate::{ErasablePtr, Erased, ErasedPtr},
+    core::{
+        fmt::{self, Debug},
+        ptr,
+        sync::atomic::{AtomicPtr, Ordering},
+    },
+};
+
+/// An [`ErasedPtr`] which can be safely shared between threads.
+///
+/// This is a thin wrapper around [`AtomicPtr`] which is never null,
+/// so it loads and stores [`ErasedPtr`] rather than raw pointers.
+/// Like `AtomicPtr`, it does not own the pointer it holds;
+/// managing the lifetime of the erased pointer is up to you.
+///
+/// # Examples
+///
+/// ```rust
+/// use {erasable::{AtomicErasedPtr, ErasablePtr}, std::sync::atomic::Ordering};
+///
+/// let cell = AtomicErasedPtr::new_erasable(Box::new(1));
+/// let two = ErasablePtr::erase(Box::new(2));
+/// let one = cell.swap(two, Ordering::AcqRel);
+/// let one: Box<i32> = unsafe { ErasablePtr::unerase(one) };
+/// let two: Box<i32> = unsafe { cell.into_erasable() };
+/// assert_eq!((*one, *two), (1, 2));
+/// ```
+#[repr(transparent)]
+pub struct AtomicErasedPtr {
+    ptr: AtomicPtr<Erased>,
+}
+
+impl AtomicErasedPtr {
+    /// Create a new atomic erased pointer.
+    #[inline]
+    pub const fn new(ptr: ErasedPtr) -> Self {
+        AtomicErasedPtr {
+            ptr: AtomicPtr::new(ptr.as_ptr()),
+        }
+    }
+
+    /// Create a new atomic erased pointer by erasing a pointer.
+    ///
+    /// The pointer is not dropped when the `AtomicErasedPtr` is dropped;
+    /// use [`AtomicErasedPtr::into_erasable`] to recover it.
+    #[inline]
+    pub fn new_erasable<P: ErasablePtr>(ptr: P) -> Self {
+        AtomicErasedPtr::new(P::erase(ptr))
+    }
+
+    /// Get a mutable reference to the erased pointer.
+    ///
+    /// This is safe because the mutable reference guarantees no other threads
+    /// are concurrently accessing the atomic pointer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut ErasedPtr {
+        // SAFETY: NonNull is repr(transparent), and we never store a null pointer
+        unsafe { &mut *(self.ptr.get_mut() as *mut *mut Erased as *mut ErasedPtr) }
+    }
+
+    /// Consume the atomic and return the contained erased pointer.
+    #[inline]
+    pub fn into_inner(self) -> ErasedPtr {
+        unsafe { ptr::NonNull::new_unchecked(self.ptr.into_inner()) }
+    }
+
+    /// Consume the atomic and unerase the contained pointer.
+    ///
+    /// # Safety
+    ///
+    /// The contained pointer must have been created by `P::erase`,
+    /// and ownership of it must not have been claimed elsewhere.
+    #[inline]
+    pub unsafe fn into_erasable<P: ErasablePtr>(self) -> P {
+        P::unerase(self.into_inner())
+    }
+
+    /// Load the erased pointer.
+    ///
+    /// See [`AtomicPtr::load`] for the semantics of `order`.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> ErasedPtr {
+        unsafe { ptr::NonNull::new_unchecked(self.ptr.load(order)) }
+    }
+
+    /// Store an erased pointer.
+    ///
+    /// See [`AtomicPtr::store`] for the semantics of `order`.
+    #[inline]
+    pub fn store(&self, ptr: ErasedPtr, order: Ordering) {
+        self.ptr.store(ptr.as_ptr(), order)
+    }
+
+    /// Store an erased pointer, returning the previous value.
+    ///
+    /// See [`AtomicPtr::swap`] for the semantics of `order`.
+    #[inline]
+    pub fn swap(&self, ptr: ErasedPtr, order: Ordering) -> ErasedPtr {
+        unsafe { ptr::NonNull::new_unchecked(self.ptr.swap(ptr.as_ptr(), order)) }
+    }
+
+    /// Store an erased pointer if the current value is the same as `current`.
+    ///
+    /// See [`AtomicPtr::compare_exchange`] for the semantics of the orderings.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: ErasedPtr,
+        new: ErasedPtr,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<ErasedPtr, ErasedPtr> {
+        match (self.ptr).compare_exchange(current.as_ptr(), new.as_ptr(), success, failure) {
+            Ok(ptr) => Ok(unsafe { ptr::NonNull::new_unchecked(ptr) }),
+            Err(ptr) => Err(unsafe { ptr::NonNull::new_unchecked(ptr) }),
+        }
+    }
+
+    /// Store an erased pointer if the current value is the same as `current`.
+    ///
+    /// Unlike [`AtomicErasedPtr::compare_exchange`], this function is allowed
+    /// to spuriously fail, which can result in more efficient code on some platforms.
+    ///
+    /// See [`AtomicPtr::compare_exchange_weak`] for the semantics of the orderings.
+    #[inline]
+    pub fn compare_exchange_weak(
+        &self,
+        current: ErasedPtr,
+        new: ErasedPtr,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<ErasedPtr, ErasedPtr> {
+        match (self.ptr).compare_exchange_weak(current.as_ptr(), new.as_ptr(), success, failure) {
+            Ok(ptr) => Ok(unsafe { ptr::NonNull::new_unchecked(ptr) }),
+            Err(ptr) => Err(unsafe { ptr::NonNull::new_unchecked(ptr) }),
+        }
+    }
+}
+
+impl Debug for AtomicErasedPtr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+impl From<ErasedPtr> for AtomicErasedPtr {
+    fn from(ptr: ErasedPtr) -> Self {
+        AtomicErasedPtr::new(ptr)
+    }
+}
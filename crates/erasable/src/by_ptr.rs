@@ -0,0 +1,91 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+/// A pointer compared by address rather than by value.
+///
+/// `Eq`, `Ord`, and `Hash` are implemented using the address of the pointee,
+/// ignoring any pointer metadata, so this can be used to key maps by identity.
+/// It works with any [`Deref`] pointer, such as `&T`, `Arc<T>`, or [`Thin<P>`](crate::Thin).
+///
+/// Note that distinct zero-sized values may share an address,
+/// and so compare equal.
+///
+/// This does not implement `Borrow<P::Target>`, as that would require
+/// hashing to be consistent with the pointee's `Hash` impl.
+///
+/// # Examples
+///
+/// ```rust
+/// use {erasable::ByPtr, std::{collections::HashSet, rc::Rc}};
+///
+/// let a = Rc::new(0);
+/// let b = Rc::new(0);
+/// let mut set = HashSet::new();
+/// set.insert(ByPtr(a.clone()));
+/// assert!(set.contains(&ByPtr(a)));
+/// assert!(!set.contains(&ByPtr(b)));
+/// ```
+#[derive(Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct ByPtr<P>(pub P);
+
+impl<P: Deref> ByPtr<P> {
+    /// The address of the pointee, without any pointer metadata.
+    pub fn addr(this: &Self) -> *const () {
+        &*this.0 as *const P::Target as *const ()
+    }
+
+    /// Extract the wrapped pointer.
+    pub fn into_inner(this: Self) -> P {
+        this.0
+    }
+}
+
+impl<P: Deref> Debug for ByPtr<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ByPtr").field(&ByPtr::addr(self)).finish()
+    }
+}
+
+impl<P: Deref> Deref for ByPtr<P> {
+    type Target = P::Target;
+    fn deref(&self) -> &P::Target {
+        &self.0
+    }
+}
+
+impl<P: Deref> Eq for ByPtr<P> {}
+
+impl<P> From<P> for ByPtr<P> {
+    fn from(this: P) -> Self {
+        ByPtr(this)
+    }
+}
+
+impl<P: Deref> Hash for ByPtr<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ByPtr::addr(self).hash(state)
+    }
+}
+
+impl<P: Deref> Ord for ByPtr<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        ByPtr::addr(self).cmp(&ByPtr::addr(other))
+    }
+}
+
+impl<P: Deref> PartialEq for ByPtr<P> {
+    fn eq(&self, other: &Self) -> bool {
+        ByPtr::addr(self) == ByPtr::addr(other)
+    }
+}
+
+impl<P: Deref> PartialOrd for ByPtr<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,137 @@
+use {
+    crate::{ErasablePtr, ErasedPtr, Thin},
+    core::{
+        fmt::{self, Debug},
+        mem::ManuallyDrop,
+        ops::{Deref, DerefMut},
+        ptr,
+    },
+};
+
+/// A debugging version of [`Thin`] which checks that the pointer roundtrips.
+///
+/// On every access, the erased pointer is unerased and erased again,
+/// and the result is asserted to be the same erased pointer. This catches
+/// [`ErasablePtr`] implementations which violate the roundtrip requirement
+/// at the point of use, rather than as later memory corruption.
+///
+/// This type is guaranteed to have the same repr as [`Thin`],
+/// so can be swapped in for a `Thin` for debugging.
+///
+/// # Panics
+///
+/// Any access panics if the wrapped pointer is observed to not roundtrip.
+///
+/// # Examples
+///
+/// ```rust
+/// use erasable::CheckedThin;
+///
+/// let thin: CheckedThin<Box<u32>> = Box::new(0).into();
+/// assert_eq!(*thin, 0);
+/// ```
+#[repr(transparent)]
+pub struct CheckedThin<P: ErasablePtr> {
+    thin: Thin<P>,
+}
+
+impl<P: ErasablePtr> From<P> for CheckedThin<P> {
+    fn from(this: P) -> Self {
+        let this = CheckedThin { thin: this.into() };
+        CheckedThin::check(&this);
+        this
+    }
+}
+
+impl<P: ErasablePtr> From<Thin<P>> for CheckedThin<P> {
+    fn from(thin: Thin<P>) -> Self {
+        let this = CheckedThin { thin };
+        CheckedThin::check(&this);
+        this
+    }
+}
+
+impl<P: ErasablePtr> From<CheckedThin<P>> for Thin<P> {
+    fn from(this: CheckedThin<P>) -> Self {
+        CheckedThin::check(&this);
+        this.thin
+    }
+}
+
+impl<P: ErasablePtr> CheckedThin<P> {
+    fn check(this: &Self) {
+        let erased: ErasedPtr = this.thin.ptr;
+        let unerased = ManuallyDrop::new(unsafe { P::unerase(erased) });
+        let reerased = P::erase(unsafe { ptr::read(&*unerased) });
+        assert_eq!(erased, reerased, "ErasablePtr impl did not roundtrip");
+    }
+
+    // noinspection RsSelfConvention
+    /// Extract the wrapped pointer.
+    pub fn into_inner(this: Self) -> P {
+        CheckedThin::check(&this);
+        Thin::into_inner(this.thin)
+    }
+
+    /// Run a closure with a borrow of the real pointer.
+    pub fn with<F, T>(this: &Self, f: F) -> T
+    where
+        F: FnOnce(&P) -> T,
+    {
+        CheckedThin::check(this);
+        let result = Thin::with(&this.thin, f);
+        CheckedThin::check(this);
+        result
+    }
+
+    /// Run a closure with a mutable borrow of the real pointer.
+    pub fn with_mut<F, T>(this: &mut Self, f: F) -> T
+    where
+        F: FnOnce(&mut P) -> T,
+    {
+        CheckedThin::check(this);
+        let result = Thin::with_mut(&mut this.thin, f);
+        CheckedThin::check(this);
+        result
+    }
+}
+
+impl<P: ErasablePtr> Clone for CheckedThin<P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        CheckedThin::with(self, |p| p.clone()).into()
+    }
+}
+
+impl<P: ErasablePtr> Debug for CheckedThin<P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        CheckedThin::with(self, |p| p.fmt(f))
+    }
+}
+
+impl<P: ErasablePtr> Deref for CheckedThin<P>
+where
+    P: Deref,
+{
+    type Target = P::Target;
+
+    fn deref(&self) -> &P::Target {
+        CheckedThin::check(self);
+        &self.thin
+    }
+}
+
+impl<P: ErasablePtr> DerefMut for CheckedThin<P>
+where
+    P: DerefMut,
+{
+    fn deref_mut(&mut self) -> &mut P::Target {
+        CheckedThin::check(self);
+        &mut self.thin
+    }
+}
@@ -0,0 +1,191 @@
+//! Erased pointers compressed to 32 bits.
+//!
+//! On 64-bit targets, a pointer is 8 bytes. If you have very many pointers
+//! into the same region of memory (such as nodes allocated in an arena),
+//! storing them as 32-bit offsets from the start of the region halves their size.
+//!
+//! A [`CompactBase`] describes the region, and compresses pointers into it
+//! to [`CompactErasedPtr`] handles. The region must be a single allocation,
+//! as the decompressed pointers are derived from the base pointer.
+//!
+//! [`CompactThin<P>`] is a typed handle to a compressed [`ErasablePtr`].
+//! As it doesn't know its base, it can't drop the pointer; you must
+//! decompress it with [`CompactThin::into_inner`] to avoid leaking it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use {
+//!     erasable::compact::{CompactBase, CompactThin},
+//!     std::ptr::NonNull,
+//! };
+//!
+//! let mut arena = vec![0u64; 1024];
+//! let arena_ptr = NonNull::from(&mut arena[..]).cast::<u64>();
+//! let base = CompactBase::new(arena_ptr.cast(), 1024 * 8, 8);
+//!
+//! let node: &mut u64 = unsafe { &mut *arena_ptr.as_ptr().add(42) };
+//! let edge = CompactThin::new(&base, node).unwrap();
+//! assert_eq!(std::mem::size_of_val(&edge), 4);
+//! unsafe { CompactThin::with(&edge, &base, |node| assert_eq!(**node, 0)) };
+//! *unsafe { CompactThin::into_inner(edge, &base) } = 1;
+//! assert_eq!(arena[42], 1);
+//! ```
+
+use {
+    crate::{ErasablePtr, ErasedPtr},
+    core::{
+        fmt::{self, Debug},
+        marker::PhantomData,
+        mem::ManuallyDrop,
+        num::NonZeroU32,
+        ptr,
+    },
+};
+
+/// A region of memory that erased pointers can be compressed relative to.
+#[derive(Debug, Clone, Copy)]
+pub struct CompactBase {
+    base: ptr::NonNull<u8>,
+    len: usize,
+    shift: u32,
+}
+
+/// An [`ErasedPtr`] compressed relative to a [`CompactBase`].
+///
+/// `Option<CompactErasedPtr>` is also 32 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompactErasedPtr {
+    raw: NonZeroU32,
+}
+
+impl CompactBase {
+    /// Describe a region of memory of `len` bytes starting at `base`.
+    ///
+    /// Only pointers aligned to `align` can be compressed, which allows the
+    /// region to be up to `align` times 4 GiB long (minus one alignment unit).
+    ///
+    /// Decompressed pointers are derived from `base`, so `base` must have
+    /// provenance over the whole region for them to be usable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two or the region is too long.
+    pub fn new(base: ptr::NonNull<u8>, len: usize, align: usize) -> Self {
+        assert!(align.is_power_of_two(), "align must be a power of two");
+        let shift = align.trailing_zeros();
+        assert!(
+            len >> shift < u32::MAX as usize,
+            "region is too long to compress into 32 bits"
+        );
+        assert!(
+            (base.as_ptr() as usize).checked_add(len).is_some(),
+            "region wraps around the address space"
+        );
+        CompactBase { base, len, shift }
+    }
+
+    /// The start of the region.
+    pub fn as_ptr(&self) -> ptr::NonNull<u8> {
+        self.base
+    }
+
+    /// Compress a pointer into this region.
+    ///
+    /// Returns `None` if the pointer is outside the region or insufficiently aligned.
+    pub fn compress(&self, ptr: ErasedPtr) -> Option<CompactErasedPtr> {
+        let offset = (ptr.as_ptr() as usize).checked_sub(self.base.as_ptr() as usize)?;
+        if offset >= self.len || offset & ((1 << self.shift) - 1) != 0 {
+            return None;
+        }
+        // NB: + 1 so that the handle is non-zero; this can't overflow (checked in new)
+        let raw = (offset >> self.shift) as u32 + 1;
+        Some(CompactErasedPtr {
+            raw: unsafe { NonZeroU32::new_unchecked(raw) },
+        })
+    }
+
+    /// Decompress a pointer previously compressed into this region.
+    ///
+    /// Returns `None` if the pointer is outside the region, which can happen
+    /// if it was compressed with a different base. Decompressing a pointer
+    /// compressed with a different base otherwise gives a pointer
+    /// which is not necessarily valid for anything.
+    pub fn decompress(&self, ptr: CompactErasedPtr) -> Option<ErasedPtr> {
+        let offset = ((ptr.raw.get() - 1) as usize).checked_mul(1 << self.shift)?;
+        if offset >= self.len {
+            return None;
+        }
+        let raw = self.base.as_ptr().wrapping_add(offset);
+        // SAFETY: offset is in the region, which doesn't wrap around the address space
+        Some(unsafe { ptr::NonNull::new_unchecked(raw.cast()) })
+    }
+}
+
+/// A compressed thin pointer.
+///
+/// This is like [`Thin`](crate::Thin), but compressed relative to a [`CompactBase`].
+/// As it doesn't know its base, it isn't dropped automatically;
+/// use [`CompactThin::into_inner`] to get the pointer back.
+#[must_use = "CompactThin leaks the pointer if not decompressed"]
+pub struct CompactThin<P: ErasablePtr> {
+    ptr: CompactErasedPtr,
+    marker: PhantomData<P>,
+}
+
+unsafe impl<P: ErasablePtr> Send for CompactThin<P> where P: Send {}
+unsafe impl<P: ErasablePtr> Sync for CompactThin<P> where P: Sync {}
+
+impl<P: ErasablePtr> CompactThin<P> {
+    /// Compress a pointer into the given region.
+    ///
+    /// If the pointer can't be compressed into the region, it is returned.
+    pub fn new(base: &CompactBase, this: P) -> Result<Self, P> {
+        let erased = P::erase(this);
+        match base.compress(erased) {
+            Some(ptr) => Ok(CompactThin {
+                ptr,
+                marker: PhantomData,
+            }),
+            None => Err(unsafe { P::unerase(erased) }),
+        }
+    }
+
+    /// Get the compressed erased pointer.
+    pub fn as_compact(this: &Self) -> CompactErasedPtr {
+        this.ptr
+    }
+
+    // noinspection RsSelfConvention
+    /// Decompress and extract the wrapped pointer.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be the same region the pointer was compressed with.
+    pub unsafe fn into_inner(this: Self, base: &CompactBase) -> P {
+        P::unerase(Self::decompress(&this, base))
+    }
+
+    /// Run a closure with a borrow of the real pointer.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be the same region the pointer was compressed with.
+    pub unsafe fn with<F, T>(this: &Self, base: &CompactBase, f: F) -> T
+    where
+        F: FnOnce(&P) -> T,
+    {
+        f(&ManuallyDrop::new(P::unerase(Self::decompress(this, base))))
+    }
+
+    fn decompress(this: &Self, base: &CompactBase) -> ErasedPtr {
+        base.decompress(this.ptr)
+            .expect("CompactThin decompressed with a different base")
+    }
+}
+
+impl<P: ErasablePtr> Debug for CompactThin<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CompactThin").field(&self.ptr).finish()
+    }
+}
@@ -0,0 +1,157 @@
+use {
+    crate::{Erasable, Erased, ErasedPtr},
+    core::{
+        fmt::{self, Debug, Pointer},
+        marker::PhantomData,
+        ptr,
+    },
+};
+
+/// A type-erased shared reference.
+///
+/// This is an [`ErasedPtr`] that remembers the lifetime of the borrow it
+/// was created from, so the borrow checker prevents it from outliving it.
+/// Create one with [`erase_ref`](crate::erase_ref).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ErasedRef<'a> {
+    ptr: ErasedPtr,
+    marker: PhantomData<&'a Erased>,
+}
+
+/// A type-erased unique reference.
+///
+/// This is an [`ErasedPtr`] that remembers the lifetime of the borrow it
+/// was created from, so the borrow checker prevents it from outliving it.
+/// Create one with [`erase_mut`](crate::erase_mut).
+#[derive(PartialEq, Eq, Hash)]
+pub struct ErasedMut<'a> {
+    ptr: ErasedPtr,
+    marker: PhantomData<&'a mut Erased>,
+}
+
+/// Erase a reference, keeping its lifetime.
+///
+/// This is usable in const contexts, and gives the same erased pointer as
+/// [`ErasablePtr::erase`](crate::ErasablePtr::erase) for `&T` if `T` uses the
+/// provided implementation of [`Erasable::erase`] (which includes all sized
+/// types and all `Erasable` impls in this crate).
+///
+/// # Examples
+///
+/// ```rust
+/// use erasable::{erase_ref, ErasedRef};
+///
+/// const TABLE: [ErasedRef<'static>; 2] = [erase_ref(&1u32), erase_ref(&2u32)];
+///
+/// let two: &u32 = unsafe { TABLE[1].unerase() };
+/// assert_eq!(*two, 2);
+/// ```
+///
+/// The erased reference can't outlive the borrow it was created from:
+///
+/// ```rust,compile_fail
+/// let erased = {
+///     let local = 0u32;
+///     erasable::erase_ref(&local)
+/// };
+/// ```
+#[inline(always)]
+pub const fn erase_ref<T: ?Sized>(this: &T) -> ErasedRef<'_> {
+    ErasedRef {
+        ptr: unsafe { ptr::NonNull::new_unchecked(this as *const T as *mut Erased) },
+        marker: PhantomData,
+    }
+}
+
+/// Erase a unique reference, keeping its lifetime.
+///
+/// This gives the same erased pointer as
+/// [`ErasablePtr::erase`](crate::ErasablePtr::erase) for `&mut T` if `T` uses the
+/// provided implementation of [`Erasable::erase`] (which includes all sized
+/// types and all `Erasable` impls in this crate).
+#[inline(always)]
+pub fn erase_mut<T: ?Sized>(this: &mut T) -> ErasedMut<'_> {
+    ErasedMut {
+        ptr: unsafe { ptr::NonNull::new_unchecked(this as *mut T as *mut Erased) },
+        marker: PhantomData,
+    }
+}
+
+impl<'a> ErasedRef<'a> {
+    /// Get the erased pointer, forgetting the lifetime.
+    #[inline(always)]
+    pub const fn as_ptr(self) -> ErasedPtr {
+        self.ptr
+    }
+
+    /// Recover the erased reference.
+    ///
+    /// # Safety
+    ///
+    /// The reference must have been erased from a `&'a T`.
+    #[inline(always)]
+    pub unsafe fn unerase<T: ?Sized + Erasable>(self) -> &'a T {
+        &*T::unerase(self.ptr).as_ptr()
+    }
+}
+
+impl<'a> ErasedMut<'a> {
+    /// Get the erased pointer, forgetting the lifetime.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> ErasedPtr {
+        self.ptr
+    }
+
+    /// Reborrow this erased reference for a shorter lifetime.
+    #[inline(always)]
+    pub fn reborrow(&mut self) -> ErasedMut<'_> {
+        ErasedMut {
+            ptr: self.ptr,
+            marker: PhantomData,
+        }
+    }
+
+    /// Recover the erased reference.
+    ///
+    /// # Safety
+    ///
+    /// The reference must have been erased from a `&'a mut T`.
+    #[inline(always)]
+    pub unsafe fn unerase<T: ?Sized + Erasable>(self) -> &'a mut T {
+        &mut *T::unerase(self.ptr).as_ptr()
+    }
+}
+
+impl<'a> From<ErasedMut<'a>> for ErasedRef<'a> {
+    #[inline(always)]
+    fn from(this: ErasedMut<'a>) -> Self {
+        ErasedRef {
+            ptr: this.ptr,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl Debug for ErasedRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErasedRef").field(&self.ptr).finish()
+    }
+}
+
+impl Pointer for ErasedRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Pointer::fmt(&self.ptr, f)
+    }
+}
+
+impl Debug for ErasedMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErasedMut").field(&self.ptr).finish()
+    }
+}
+
+impl Pointer for ErasedMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Pointer::fmt(&self.ptr, f)
+    }
+}
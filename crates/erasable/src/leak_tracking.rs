@@ -0,0 +1,53 @@
+//! Diagnostics for finding leaked thin pointers.
+//!
+//! Erased pointers are invisible to most leak detection tooling, as the
+//! allocation is only reachable through an opaque pointer. With the
+//! `leak_tracking` feature enabled, every [`Thin`] pointer is counted from
+//! its creation until it is dropped or unwrapped with [`Thin::into_inner`],
+//! so you can check that a subsystem cleans up the thin pointers it creates.
+//!
+//! A `Thin` erased with [`ErasablePtr::erase`] (or converted with
+//! [`Thin::into_ffi`]) is still considered live until it is unerased and dropped.
+//!
+//! Call sites are not tracked, as that would require storing them in the `Thin`.
+//! To narrow down a leak, compare the count before and after suspect code.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use erasable::{leak_tracking, Thin};
+//!
+//! let before = leak_tracking::live_thin_count();
+//! let thin: Thin<Box<u32>> = Box::new(0).into();
+//! assert_eq!(leak_tracking::live_thin_count(), before + 1);
+//! drop(thin);
+//! assert_eq!(leak_tracking::live_thin_count(), before);
+//! ```
+//!
+//! [`ErasablePtr::erase`]: crate::ErasablePtr::erase
+//! [`Thin`]: crate::Thin
+//! [`Thin::into_inner`]: crate::Thin::into_inner
+//! [`Thin::into_ffi`]: crate::Thin::into_ffi
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of [`Thin`] pointers which are currently live.
+///
+/// This is a global count across all threads and all `Thin<P>` types.
+///
+/// [`Thin`]: crate::Thin
+pub fn live_thin_count() -> usize {
+    LIVE.load(Ordering::Relaxed)
+}
+
+#[inline(always)]
+pub(crate) fn created() {
+    LIVE.fetch_add(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn destroyed() {
+    LIVE.fetch_sub(1, Ordering::Relaxed);
+}
@@ -19,14 +19,22 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 #![no_std]
+#![cfg_attr(feature = "ptr_metadata", feature(ptr_metadata))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, rc, sync};
+#[cfg(has_core_error)]
+use core::error::Error;
+#[cfg(feature = "cstr")]
+use core::ffi::CStr;
 use core::{
     cmp::Ordering,
+    ffi::c_void,
     fmt::{self, Debug, Display, Formatter, Pointer},
     future::Future,
     hash::{Hash, Hasher},
@@ -34,10 +42,19 @@ use core::{
     marker::PhantomData,
     mem::ManuallyDrop,
     ops::{Deref, DerefMut},
+    panic::{RefUnwindSafe, UnwindSafe},
     pin::Pin,
     ptr,
     task::{Context, Poll},
 };
+#[cfg(all(not(has_core_error), feature = "std"))]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::{
+    io::{self, BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write},
+    string::String,
+    vec::Vec,
+};
 
 /// A thin, type-erased pointer.
 ///
@@ -47,6 +64,20 @@ use core::{
 /// The current implementation uses a `struct Erased` with size 0 and align 1.
 /// If you want to offset the pointer, make sure to cast to a `u8` or other known type pointer first.
 /// When `Erased` becomes an extern type, it will properly have unknown size and align.
+///
+/// # FFI
+///
+/// `ErasedPtr` is guaranteed to be ABI compatible with a non-null `*mut c_void`,
+/// and `Option<ErasedPtr>` with a nullable `*mut c_void`.
+/// Convert between the two with [`NonNull::cast`](ptr::NonNull::cast):
+///
+/// ```rust
+/// use {core::{ffi::c_void, ptr::NonNull}, erasable::ErasedPtr};
+///
+/// let erased: ErasedPtr = erasable::erase_ref(&0u32).as_ptr();
+/// let void: NonNull<c_void> = erased.cast();
+/// let erased: ErasedPtr = void.cast();
+/// ```
 pub type ErasedPtr = ptr::NonNull<Erased>;
 
 #[cfg(not(has_extern_type))]
@@ -87,6 +118,11 @@ impl Debug for Erased {
 /// [`Lazy`](https://docs.rs/once_cell/1.2/once_cell/sync/struct.Lazy.html), however,
 /// `Deref`s into its own location, and as such, can not implement `ErasablePtr`.
 ///
+/// For the same reason, [`ManuallyDrop<P>`](ManuallyDrop) does not implement `ErasablePtr`:
+/// it `Deref`s to the `P` stored inline. If you need to manage the drop of a thin pointer
+/// manually, wrap the erased pointer instead; `ManuallyDrop<Thin<P>>` works as expected,
+/// and [`Thin::into_inner`] recovers the `P` when you want to drop it.
+///
 /// This is similar to (but distinct from!) the guarantees required by
 /// [`Pin`](https://doc.rust-lang.org/std/pin/struct.Pin.html) or
 /// [`StableDeref`](https://docs.rs/stable_deref_trait/1/stable_deref_trait/trait.StableDeref.html).
@@ -123,6 +159,8 @@ impl Debug for Erased {
 /// dbg!(thin_box);
 /// ```
 ///
+/// Newtype delegation like this can also be written with [`impl_erasable_for_newtype!`].
+///
 /// # Counterexamples
 //  These are available to be run under miri to observe UB as tests/this_is_ub_examples.rs!
 ///
@@ -237,6 +275,22 @@ pub unsafe trait Erasable {
     /// The erased pointer must have been created by `erase`ing a valid pointer.
     unsafe fn unerase(this: ErasedPtr) -> ptr::NonNull<Self>;
 
+    /// Unerase a dangling erased pointer, without reading the pointee.
+    ///
+    /// A dangling weak pointer (created by `Weak::new()`) doesn't point to an
+    /// allocation, so for unsized types there is no metadata to be read.
+    /// Instead, this is used to make up metadata that is valid for a dangling
+    /// pointer, such as a trailing slice length of zero, so the weak pointer
+    /// can be roundtripped through [`ErasablePtr`].
+    ///
+    /// The default implementation returns `None`, which means that erasing
+    /// a dangling weak pointer to `Self` is not supported and aborts the process.
+    /// It is always supported for sized types.
+    fn unerase_dangling(this: ErasedPtr) -> Option<ptr::NonNull<Self>> {
+        let _ = this;
+        None
+    }
+
     /// Whether this implementor has acknowledged the 1.1.0 update to
     /// `unerase`'s documented implementation requirements.
     ///
@@ -271,11 +325,66 @@ pub unsafe trait Erasable {
 }
 
 /// Erase a pointer.
+///
+/// This is the provided implementation of [`Erasable::erase`], and is usable in const contexts.
 #[inline(always)]
-pub fn erase<T: ?Sized>(ptr: ptr::NonNull<T>) -> ErasedPtr {
+pub const fn erase<T: ?Sized>(ptr: ptr::NonNull<T>) -> ErasedPtr {
     unsafe { ptr::NonNull::new_unchecked(ptr.as_ptr() as *mut Erased) }
 }
 
+/// Read a metadata prefix stored at the start of an erased pointee.
+///
+/// This is a helper for implementing [`Erasable::unerase`]. It performs the
+/// raw, reference-free read that `unerase` is required to use, and only reads
+/// `size_of::<M>()` bytes, so it doesn't race with writes to the rest of the pointee.
+///
+/// # Safety
+///
+/// The erased pointer must be valid for reads of `M` and aligned for `M`,
+/// and the start of the pointee must be an initialized `M`.
+///
+/// # Examples
+///
+/// ```rust
+/// use {erasable::{Erasable, ErasedPtr}, core::ptr::NonNull};
+///
+/// #[repr(C)]
+/// struct Dst<T: ?Sized> {
+///     len: usize,
+///     data: T,
+/// }
+///
+/// unsafe impl Erasable for Dst<[u8]> {
+///     unsafe fn unerase(this: ErasedPtr) -> NonNull<Self> {
+///         let len: usize = erasable::read_prefix(this);
+///         let raw = erasable::unerase_slice::<u8>(this, len);
+///         NonNull::new_unchecked(raw.as_ptr() as *mut Self)
+///     }
+///
+///     const ACK_1_1_0: bool = true;
+/// }
+/// ```
+#[inline(always)]
+pub unsafe fn read_prefix<M: Copy>(this: ErasedPtr) -> M {
+    ptr::read(this.as_ptr().cast::<M>())
+}
+
+/// Create a slice pointer with the given length from an erased pointer.
+///
+/// This is a helper for implementing [`Erasable::unerase`] for slice-tailed types.
+/// The returned pointer is derived directly from the erased pointer (keeping its
+/// provenance), and can be cast to a custom slice-tailed type with an `as` cast.
+/// See [`read_prefix`] for an example.
+///
+/// Note that the length is the length of the trailing slice, not the
+/// number of `T` that fit in the pointee; casting the pointer only changes
+/// the pointee type, and keeps the length metadata as-is.
+#[inline(always)]
+pub fn unerase_slice<T>(this: ErasedPtr, len: usize) -> ptr::NonNull<[T]> {
+    let raw = ptr::slice_from_raw_parts_mut(this.as_ptr().cast::<T>(), len);
+    unsafe { ptr::NonNull::new_unchecked(raw) }
+}
+
 /// Wrapper struct to create thin pointer types.
 ///
 /// This type is guaranteed to have the same repr as [`ErasedPtr`].
@@ -294,6 +403,14 @@ pub fn erase<T: ?Sized>(ptr: ptr::NonNull<T>) -> ErasedPtr {
 /// Note that this uses a `Sized` type: `[i32; 10]`.
 /// This library does not provide erasable `?Sized` types.
 /// For that, try out [`slice-dst`](https://lib.rs/slice-dst).
+///
+/// # FFI
+///
+/// As `Thin<P>` has the same repr as [`ErasedPtr`], it is also ABI compatible
+/// with a non-null `*mut c_void`, and `Option<Thin<P>>` with a nullable one.
+/// (The `improper_ctypes` lint does not know about the latter, however.)
+/// Using `Thin<P>` directly in `extern` signatures is thus allowed,
+/// but [`Thin::into_ffi`] and [`Thin::from_ffi`] make the ownership transfer explicit.
 #[repr(transparent)]
 pub struct Thin<P: ErasablePtr> {
     ptr: ErasedPtr,
@@ -302,10 +419,15 @@ pub struct Thin<P: ErasablePtr> {
 
 unsafe impl<P: ErasablePtr> Send for Thin<P> where P: Send {}
 unsafe impl<P: ErasablePtr> Sync for Thin<P> where P: Sync {}
+impl<P: ErasablePtr> Unpin for Thin<P> where P: Unpin {}
+impl<P: ErasablePtr> UnwindSafe for Thin<P> where P: UnwindSafe {}
+impl<P: ErasablePtr> RefUnwindSafe for Thin<P> where P: RefUnwindSafe {}
 
 impl<P: ErasablePtr> From<P> for Thin<P> {
     #[inline(always)]
     fn from(this: P) -> Self {
+        #[cfg(feature = "leak_tracking")]
+        leak_tracking::created();
         Thin::<P> {
             ptr: P::erase(this),
             marker: PhantomData,
@@ -323,6 +445,8 @@ impl<P: ErasablePtr> Thin<P> {
     // `Into` can't be impl'd because it conflicts with the reflexive impl
     /// Extract the wrapped pointer.
     pub fn into_inner(this: Self) -> P {
+        #[cfg(feature = "leak_tracking")]
+        leak_tracking::destroyed();
         unsafe { P::unerase(ManuallyDrop::new(this).ptr) }
     }
 
@@ -342,7 +466,11 @@ impl<P: ErasablePtr> Thin<P> {
         // SAFETY: guard is required to write potentially changed pointer value, even on unwind
         let mut this = unsafe {
             scopeguard::guard(P::unerase(this.ptr), |unerased| {
-                ptr::write(this, Thin::from(unerased))
+                // NB: only store if changed, as most closures don't change the pointer
+                let erased = P::erase(unerased);
+                if erased != this.ptr {
+                    this.ptr = erased;
+                }
             })
         };
         f(&mut this)
@@ -352,10 +480,66 @@ impl<P: ErasablePtr> Thin<P> {
     pub fn ptr_eq<Q: ErasablePtr>(this: &Self, that: &Thin<Q>) -> bool {
         this.ptr == that.ptr
     }
+
+    /// Transform the wrapped pointer into a different thin pointer.
+    ///
+    /// The pointer is moved into the closure, so if it panics,
+    /// the pointer is dropped as normal during unwinding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {erasable::Thin, std::sync::Arc};
+    ///
+    /// let thin: Thin<Box<u32>> = Box::new(0).into();
+    /// let thin: Thin<Arc<u32>> = Thin::map(thin, Arc::from);
+    /// ```
+    pub fn map<Q: ErasablePtr, F>(this: Self, f: F) -> Thin<Q>
+    where
+        F: FnOnce(P) -> Q,
+    {
+        f(Thin::into_inner(this)).into()
+    }
+
+    /// Convert into an untyped pointer suitable for passing over FFI.
+    ///
+    /// Ownership of the pointer is transferred to the returned raw pointer;
+    /// it must be passed back to [`Thin::from_ffi`] to avoid leaking it.
+    pub fn into_ffi(this: Self) -> ptr::NonNull<c_void> {
+        ManuallyDrop::new(this).ptr.cast()
+    }
+
+    /// Recover a thin pointer previously converted with [`Thin::into_ffi`].
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have come from `Thin::<P>::into_ffi` (with the same `P`),
+    /// and must not have been recovered already.
+    pub unsafe fn from_ffi(ptr: ptr::NonNull<c_void>) -> Self {
+        Thin {
+            ptr: ptr.cast(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Make a mutable reference into the pointee, cloning it if it is shared.
+    ///
+    /// See [`MakeMut`] for more details.
+    pub fn make_mut(this: &mut Self) -> &mut P::Target
+    where
+        P: MakeMut,
+    {
+        // SAFETY: MakeMut guarantees the reference is independent of the location of P,
+        // so it is still valid after the (potentially new) pointer is written back.
+        let ptr: *mut P::Target = Thin::with_mut(this, |p| P::make_mut(p) as *mut _);
+        unsafe { &mut *ptr }
+    }
 }
 
 impl<P: ErasablePtr> Drop for Thin<P> {
     fn drop(&mut self) {
+        #[cfg(feature = "leak_tracking")]
+        leak_tracking::destroyed();
         unsafe { P::unerase(self.ptr) };
     }
 }
@@ -382,6 +566,31 @@ where
 
 // BorrowMut conflicts with reflexive impl
 
+// NB: BufRead goes through Deref, as the buffer borrowed by fill_buf
+// must live in the deref target, not in the transient unerased P.
+#[cfg(feature = "std")]
+impl<P: ErasablePtr> BufRead for Thin<P>
+where
+    P: Read + DerefMut,
+    P::Target: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        (**self).fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        (**self).consume(amt)
+    }
+
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_until(byte, buf)
+    }
+
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        (**self).read_line(buf)
+    }
+}
+
 impl<P: ErasablePtr> Clone for Thin<P>
 where
     P: Clone,
@@ -393,6 +602,60 @@ where
 
 // CoerceUnsized is unstable
 
+/// Unsizes a thin pointer using the `unsize` crate.
+///
+/// This delegates to the coercion of the wrapped pointer, so it is available
+/// whenever `P` implements `CoerciblePtr` and the coerced pointer is erasable.
+/// Note that the target of the coercion must be [`Erasable`] to be put in a `Thin`,
+/// so this is not useful for coercing to trait objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use {erasable::*, std::ptr};
+/// use unsize::{Coercion, CoerceUnsize};
+///
+/// #[repr(C)]
+/// struct Counted<T: ?Sized> {
+///     len: usize,
+///     data: T,
+/// }
+///
+/// unsafe impl Erasable for Counted<[u8]> {
+///     unsafe fn unerase(this: ErasedPtr) -> ptr::NonNull<Self> {
+///         let len: usize = ptr::read(this.as_ptr().cast());
+///         let raw = ptr::slice_from_raw_parts_mut(this.as_ptr().cast::<u8>(), len);
+///         ptr::NonNull::new_unchecked(raw as *mut Self)
+///     }
+///
+///     const ACK_1_1_0: bool = true;
+/// }
+///
+/// let counted = Counted { len: 4, data: [0u8, 1, 2, 3] };
+/// let thin: Thin<&Counted<[u8; 4]>> = (&counted).into();
+/// // SAFETY: this is a builtin unsizing coercion
+/// let coercion = unsafe { Coercion::new(|p| p as *const Counted<[u8]>) };
+/// let thin: Thin<&Counted<[u8]>> = thin.unsize(coercion);
+/// assert_eq!(thin.data, [0, 1, 2, 3]);
+/// ```
+#[cfg(feature = "unsize")]
+unsafe impl<P: ErasablePtr, U: ?Sized> unsize::CoerciblePtr<U> for Thin<P>
+where
+    P: unsize::CoerciblePtr<U>,
+    P::Output: ErasablePtr,
+{
+    type Pointee = P::Pointee;
+    type Output = Thin<P::Output>;
+
+    fn as_sized_ptr(&mut self) -> *mut P::Pointee {
+        Thin::with_mut(self, |p| p.as_sized_ptr())
+    }
+
+    unsafe fn replace_ptr(self, new: *mut U) -> Thin<P::Output> {
+        Thin::into_inner(self).replace_ptr(new).into()
+    }
+}
+
 impl<P: ErasablePtr> Debug for Thin<P>
 where
     P: Debug,
@@ -402,6 +665,15 @@ where
     }
 }
 
+impl<P: ErasablePtr> Default for Thin<P>
+where
+    P: Default,
+{
+    fn default() -> Self {
+        P::default().into()
+    }
+}
+
 impl<P: ErasablePtr> Deref for Thin<P>
 where
     P: Deref,
@@ -429,6 +701,19 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, P: ErasablePtr> serde::Deserialize<'de> for Thin<P>
+where
+    P: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        P::deserialize(deserializer).map(Thin::from)
+    }
+}
+
 // DispatchFromDyn is unstable
 
 impl<P: ErasablePtr> Display for Thin<P>
@@ -455,10 +740,44 @@ where
 
 impl<P: ErasablePtr> Eq for Thin<P> where P: Eq {}
 
+// NB: Error goes through Deref, as the error returned by source
+// must live in the deref target, not in the transient unerased P.
+#[cfg(any(has_core_error, feature = "std"))]
+impl<P: ErasablePtr> Error for Thin<P>
+where
+    P: Debug + Display + Deref,
+    P::Target: Error,
+{
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        (**self).description()
+    }
+
+    #[allow(deprecated)]
+    fn cause(&self) -> Option<&dyn Error> {
+        (**self).cause()
+    }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        (**self).source()
+    }
+}
+
 impl<P: ErasablePtr> ExactSizeIterator for Thin<P> where P: ExactSizeIterator {}
 
+impl<P: ErasablePtr, A> Extend<A> for Thin<P>
+where
+    P: Extend<A>,
+{
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        Thin::with_mut(self, |p| p.extend(iter))
+    }
+}
+
 // Fn, FnMut, FnOnce are unstable to implement
 
+// IntoIterator conflicts with the blanket impl for Iterator
+
 impl<P: ErasablePtr, A> FromIterator<A> for Thin<P>
 where
     P: FromIterator<A>,
@@ -593,6 +912,16 @@ where
     }
 }
 
+// NB: the reverse (P: PartialEq<Thin<P>>) can't be impl'd because P is uncovered
+impl<P: ErasablePtr> PartialEq<P> for Thin<P>
+where
+    P: PartialEq,
+{
+    fn eq(&self, other: &P) -> bool {
+        Thin::with(self, |p| p.eq(other))
+    }
+}
+
 impl<P: ErasablePtr> PartialOrd for Thin<P>
 where
     P: PartialOrd,
@@ -602,6 +931,15 @@ where
     }
 }
 
+impl<P: ErasablePtr> PartialOrd<P> for Thin<P>
+where
+    P: PartialOrd,
+{
+    fn partial_cmp(&self, other: &P) -> Option<Ordering> {
+        Thin::with(self, |p| p.partial_cmp(other))
+    }
+}
+
 impl<P: ErasablePtr> Pointer for Thin<P>
 where
     P: Pointer,
@@ -611,6 +949,122 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<P: ErasablePtr> serde::Serialize for Thin<P>
+where
+    P: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Thin::with(self, |p| p.serialize(serializer))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: ErasablePtr> Read for Thin<P>
+where
+    P: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Thin::with_mut(self, |p| p.read(buf))
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        Thin::with_mut(self, |p| p.read_vectored(bufs))
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        Thin::with_mut(self, |p| p.read_to_end(buf))
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        Thin::with_mut(self, |p| p.read_to_string(buf))
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        Thin::with_mut(self, |p| p.read_exact(buf))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: ErasablePtr> Seek for Thin<P>
+where
+    P: Seek,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        Thin::with_mut(self, |p| p.seek(pos))
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Thin::with_mut(self, |p| p.stream_position())
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<P: ErasablePtr> futures_core::Stream for Thin<P>
+where
+    P: futures_core::Stream,
+{
+    type Item = P::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        unsafe {
+            Thin::with_mut(self.get_unchecked_mut(), |this| {
+                Pin::new_unchecked(this).poll_next(cx)
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Thin::with(self, |p| p.size_hint())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: ErasablePtr> Write for Thin<P>
+where
+    P: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Thin::with_mut(self, |p| p.write(buf))
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        Thin::with_mut(self, |p| p.write_vectored(bufs))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Thin::with_mut(self, |p| p.flush())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        Thin::with_mut(self, |p| p.write_all(buf))
+    }
+
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> io::Result<()> {
+        Thin::with_mut(self, |p| p.write_fmt(fmt))
+    }
+}
+
+impl<P: ErasablePtr> fmt::Write for Thin<P>
+where
+    P: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        Thin::with_mut(self, |p| p.write_str(s))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        Thin::with_mut(self, |p| p.write_char(c))
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        Thin::with_mut(self, |p| p.write_fmt(args))
+    }
+}
+
 // ~~~ impl Eraseable ~~~ //
 
 unsafe impl<T: Sized> Erasable for T {
@@ -619,6 +1073,34 @@ unsafe impl<T: Sized> Erasable for T {
         this.cast()
     }
 
+    fn unerase_dangling(this: ErasedPtr) -> Option<ptr::NonNull<T>> {
+        Some(this.cast())
+    }
+
+    const ACK_1_1_0: bool = true;
+}
+
+/// `CStr` is erasable because its length is recoverable from the nul terminator.
+///
+/// Note that this means that `unerase` is *O(n)* in the length of the string,
+/// as it has to scan for the nul terminator (equivalent to `strlen`) every time.
+/// `Thin<Box<CStr>>` trades that cost for being a single word in size;
+/// if you access the string frequently, a `Box<CStr>` may be the better choice.
+#[cfg(feature = "cstr")]
+unsafe impl Erasable for CStr {
+    unsafe fn unerase(this: ErasedPtr) -> ptr::NonNull<CStr> {
+        // NB: read byte by byte with raw reads; CStr::from_ptr would create a reference.
+        let start = this.as_ptr().cast::<u8>();
+        let mut len = 0;
+        while ptr::read(start.add(len)) != 0 {
+            len += 1;
+        }
+        // the nul terminator is included in the slice
+        let raw = ptr::slice_from_raw_parts_mut(start, len + 1);
+        // SAFETY: this is the same cast std does in CStr::from_bytes_with_nul_unchecked
+        ptr::NonNull::new_unchecked(raw as *mut CStr)
+    }
+
     const ACK_1_1_0: bool = true;
 }
 
@@ -720,13 +1202,181 @@ macro_rules! impl_erasable {
 }
 
 #[cfg(feature = "alloc")]
-impl_erasable!(
-    for<T> Box<T>,
-    sync::Arc<T>,
-    sync::Weak<T>,
-    rc::Rc<T>,
-    rc::Weak<T>,
-);
+impl_erasable!(for<T> Box<T>, sync::Arc<T>, rc::Rc<T>,);
+
+// NB: `Weak::new()` creates a dangling weak pointer, which doesn't point to an allocation.
+// This is fine for sized `T`, as `Erasable for T: Sized` doesn't read the pointee,
+// but an unsized `T` (coerced from a sized `Weak::new()`) has nowhere to store its metadata.
+// We keep the sentinel address when erasing, and recognize it when unerasing to make up
+// metadata with `Erasable::unerase_dangling` instead of reading through the dangling pointer.
+#[cfg(feature = "alloc")]
+macro_rules! impl_erasable_weak {
+    (for<$T:ident> $($(#[$meta:meta])* $ty:ty),* $(,)?) => {$(
+        $(#[$meta])*
+        /// # Aborts
+        ///
+        /// Erasing a dangling weak pointer (one created by `Weak::new()`) aborts
+        /// the process if `T` doesn't support [`Erasable::unerase_dangling`].
+        unsafe impl<$T: ?Sized> ErasablePtr for $ty
+        where
+            T: Erasable,
+        {
+            #[inline]
+            fn erase(this: Self) -> ErasedPtr {
+                let ptr = unsafe { ptr::NonNull::new_unchecked(<$ty>::into_raw(this) as *mut _) };
+                let erased = T::erase(ptr);
+                if is_dangling(erased) && T::unerase_dangling(erased).is_none() {
+                    // NB: we can't panic here, as `Thin::with_mut` can't recover from it.
+                    //     A dangling weak pointer doesn't own anything, so it's fine to leak.
+                    abort("cannot erase a dangling Weak to an unsized type");
+                }
+                erased
+            }
+
+            #[inline]
+            unsafe fn unerase(this: ErasedPtr) -> Self {
+                let ptr = if is_dangling(this) {
+                    T::unerase_dangling(this).unwrap_unchecked()
+                } else {
+                    T::unerase(this)
+                };
+                Self::from_raw(ptr.as_ptr())
+            }
+        }
+    )*}
+}
+
+/// Whether the erased pointer is the sentinel address used by std's `Weak::new()`.
+#[cfg(feature = "alloc")]
+fn is_dangling(this: ErasedPtr) -> bool {
+    this.as_ptr().cast::<u8>() as usize == usize::MAX
+}
+
+/// Abort the process by panicking while panicking, as `core` has no `abort`.
+#[cfg(feature = "alloc")]
+#[cold]
+fn abort(msg: &str) -> ! {
+    struct Abort<'a>(&'a str);
+    impl Drop for Abort<'_> {
+        fn drop(&mut self) {
+            panic!("{}", self.0)
+        }
+    }
+    let _abort = Abort(msg);
+    panic!("{}", msg)
+}
+
+#[cfg(feature = "alloc")]
+impl_erasable_weak!(for<T> sync::Weak<T>, rc::Weak<T>,);
+
+/// A shared pointer that supports clone-on-write mutable access.
+///
+/// This is used to provide [`Thin::make_mut`].
+///
+/// # Safety
+///
+/// The reference returned by `make_mut` must be to the `Deref` target of the pointer,
+/// and as such be independent of the location of the pointer (see [`ErasablePtr`]).
+pub unsafe trait MakeMut: Deref {
+    /// Make a mutable reference into the pointee, cloning it if it is shared.
+    ///
+    /// See [`Arc::make_mut`](sync::Arc::make_mut) for an example.
+    fn make_mut(this: &mut Self) -> &mut Self::Target;
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Clone> MakeMut for sync::Arc<T> {
+    fn make_mut(this: &mut Self) -> &mut T {
+        sync::Arc::make_mut(this)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Clone> MakeMut for rc::Rc<T> {
+    fn make_mut(this: &mut Self) -> &mut T {
+        rc::Rc::make_mut(this)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+mod atomic;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicErasedPtr;
+
+mod by_ptr;
+pub use by_ptr::ByPtr;
+
+mod checked;
+pub use checked::CheckedThin;
+
+#[cfg(feature = "compact")]
+pub mod compact;
+
+mod erased_ref;
+#[cfg(feature = "leak_tracking")]
+pub mod leak_tracking;
+pub use erased_ref::{erase_mut, erase_ref, ErasedMut, ErasedRef};
+
+mod thin_ref;
+pub use thin_ref::{ThinMut, ThinRef};
+
+#[cfg(feature = "triomphe")]
+mod triomphe_impls;
+
+#[cfg(feature = "ptr_metadata")]
+mod with_metadata;
+#[cfg(feature = "ptr_metadata")]
+pub use with_metadata::WithMetadata;
+
+pub mod testing;
+
+/// Implement [`ErasablePtr`] for a newtype by delegating to the wrapped pointer.
+///
+/// The newtype must be a tuple struct with a single field, and the impl
+/// is written as `unsafe impl<..> ErasablePtr for Wrapper<..>(Inner);`.
+/// It is an `unsafe impl` as the same requirements apply as to a manual impl
+/// of `ErasablePtr`: notably, the newtype must not `Deref` into itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use erasable::{Thin, impl_erasable_for_newtype};
+///
+/// #[derive(Debug)]
+/// struct MyBox<T: ?Sized>(Box<T>);
+///
+/// impl_erasable_for_newtype! {
+///     // SAFETY: MyBox is a plain wrapper of Box
+///     unsafe impl<T: ?Sized> ErasablePtr for MyBox<T>(Box<T>);
+/// }
+///
+/// let thin: Thin<MyBox<u32>> = MyBox(Box::new(0)).into();
+/// dbg!(thin);
+/// ```
+#[macro_export]
+macro_rules! impl_erasable_for_newtype {
+    {$(
+        $(#[$meta:meta])*
+        unsafe impl<$($T:ident $(: ?$Sized:ident)?),* $(,)?>
+            ErasablePtr for $Wrapper:ident<$($U:ty),* $(,)?>($Inner:ty);
+    )*} => {$(
+        $(#[$meta])*
+        unsafe impl<$($T $(: ?$Sized)?),*> $crate::ErasablePtr for $Wrapper<$($U),*>
+        where
+            $Inner: $crate::ErasablePtr,
+        {
+            #[inline]
+            fn erase(this: Self) -> $crate::ErasedPtr {
+                <$Inner as $crate::ErasablePtr>::erase(this.0)
+            }
+
+            #[inline]
+            unsafe fn unerase(this: $crate::ErasedPtr) -> Self {
+                $Wrapper(<$Inner as $crate::ErasablePtr>::unerase(this))
+            }
+        }
+    )*};
+}
 
 #[cfg(has_never)]
 unsafe impl ErasablePtr for ! {
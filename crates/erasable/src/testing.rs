@@ -0,0 +1,168 @@
+//! Conformance checks for implementors of [`ErasablePtr`] and [`Erasable`].
+//!
+//! The functions in this module exercise an implementation through the same
+//! patterns that [`Thin`] and other erased containers use, and panic if they
+//! observe the implementation breaking one of the documented requirements.
+//!
+//! Most of the requirements of these traits are about provenance and aliasing,
+//! which can't be checked at runtime. These functions are designed to be run
+//! under [Miri](https://github.com/rust-lang/miri), which will catch the
+//! violations that slip through the assertions made here.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use erasable::{testing, Erasable, ErasablePtr, ErasedPtr};
+//!
+//! struct MyBox<T: ?Sized>(Box<T>);
+//!
+//! unsafe impl<T: ?Sized> ErasablePtr for MyBox<T>
+//! where
+//!     T: Erasable
+//! {
+//!     fn erase(this: Self) -> ErasedPtr {
+//!         ErasablePtr::erase(this.0)
+//!     }
+//!
+//!     unsafe fn unerase(this: ErasedPtr) -> Self {
+//!         Self(ErasablePtr::unerase(this))
+//!     }
+//! }
+//!
+//! testing::assert_erasable_ptr_roundtrip(|| MyBox(Box::new(0u32)));
+//! ```
+
+use {
+    crate::{Erasable, ErasablePtr, ErasedPtr, Thin},
+    core::{
+        mem::{self, MaybeUninit},
+        ops::Deref,
+        ptr,
+    },
+};
+
+/// Check that an [`ErasablePtr`] implementation roundtrips correctly.
+///
+/// `make` is called multiple times to create fresh pointers, and every pointer
+/// created is dropped normally (through its own `Drop` impl) before returning.
+///
+/// This checks that:
+///
+/// - erasing and unerasing a pointer gives back the same erased pointer,
+/// - borrowing the pointer through [`Thin::with`] does not change it,
+/// - [`Thin::with_mut`] writes back the (potentially changed) pointer,
+/// - multiple erased pointers can be live at the same time, and
+/// - pointers are dropped exactly once when unerased and dropped.
+///
+/// # Panics
+///
+/// Panics if the implementation is observed to not roundtrip.
+pub fn assert_erasable_ptr_roundtrip<P: ErasablePtr>(make: impl Fn() -> P) {
+    // erase/unerase
+    let erased = P::erase(make());
+    let unerased = unsafe { P::unerase(erased) };
+    let reerased = P::erase(unerased);
+    assert_eq!(erased, reerased, "erase(unerase(ptr)) did not roundtrip");
+    drop(unsafe { P::unerase(reerased) });
+
+    // multiple live erased pointers
+    let a = P::erase(make());
+    let b = P::erase(make());
+    let a = unsafe { P::unerase(a) };
+    let b = unsafe { P::unerase(b) };
+    drop(b);
+    drop(a);
+
+    // Thin
+    let mut thin = Thin::from(make());
+    let before = ErasablePtr::erase(unsafe { ptr::read(&thin) });
+    Thin::with(&thin, |p| {
+        assert_eq!(
+            before,
+            P::erase(unsafe { ptr::read(p) }),
+            "erased pointer changed while borrowed"
+        );
+    });
+    let after = ErasablePtr::erase(unsafe { ptr::read(&thin) });
+    assert_eq!(before, after, "Thin::with changed the erased pointer");
+    let mut witness = None;
+    Thin::with_mut(&mut thin, |p| {
+        witness = Some(P::erase(unsafe { ptr::read(p) }));
+    });
+    let after = ErasablePtr::erase(unsafe { ptr::read(&thin) });
+    assert_eq!(witness, Some(after), "Thin::with_mut did not write back");
+    Thin::with_mut(&mut thin, |p| *p = make());
+    drop(Thin::into_inner(thin));
+    drop(Thin::from(make()));
+}
+
+/// Check that an [`ErasablePtr`] implementation that implements `Deref`
+/// dereferences to a location independent of the pointer itself.
+///
+/// This additionally runs [`assert_erasable_ptr_roundtrip`].
+///
+/// # Panics
+///
+/// Panics if the implementation `Deref`s into its own location,
+/// or if dereferencing through [`Thin`] gives a different address.
+pub fn assert_erasable_ptr_deref<P: ErasablePtr + Deref>(make: impl Fn() -> P) {
+    assert_erasable_ptr_roundtrip(&make);
+
+    let p = make();
+    let target = &*p as *const P::Target as *const u8 as usize;
+    let start = &p as *const P as usize;
+    let end = start + mem::size_of::<P>();
+    assert!(
+        !(start..end).contains(&target) || mem::size_of_val(&*p) == 0,
+        "pointer dereferences into its own location"
+    );
+
+    let thin = Thin::from(p);
+    let thin_target = &*thin as *const P::Target as *const u8 as usize;
+    assert_eq!(
+        target, thin_target,
+        "Thin dereferenced to a different address"
+    );
+}
+
+/// Check that an [`Erasable`] implementation roundtrips a pointer.
+///
+/// Both the address and the metadata of the pointer must survive the roundtrip.
+/// The pointer is used for both reads and writes afterwards (under Miri, this
+/// checks that `unerase` preserved the provenance of the erased pointer).
+///
+/// # Panics
+///
+/// Panics if the implementation does not roundtrip the pointer,
+/// or if the implementation has not acknowledged the 1.1.0 semantics
+/// (see [`Erasable::ACK_1_1_0`]).
+///
+/// # Safety
+///
+/// The pointer must be valid for reads and writes, as if it were `&mut T`.
+pub unsafe fn assert_erasable_roundtrip<T: ?Sized + Erasable>(this: ptr::NonNull<T>) {
+    assert!(
+        T::ACK_1_1_0,
+        "Erasable impl did not acknowledge 1.1.0 semantics"
+    );
+
+    let erased: ErasedPtr = T::erase(this);
+    let unerased = T::unerase(erased);
+    assert!(
+        ptr::eq(this.as_ptr(), unerased.as_ptr()),
+        "unerase(erase(ptr)) did not roundtrip"
+    );
+
+    // The erased pointer is still valid after an unerase, and the original
+    // pointer is still usable after unerasing (no unique reborrow happened).
+    let again = T::unerase(erased);
+    assert!(ptr::eq(this.as_ptr(), again.as_ptr()));
+    let size = mem::size_of_val(&*this.as_ptr());
+    // NB: bytes may be padding, so copy them as MaybeUninit
+    let bytes = this.as_ptr() as *mut MaybeUninit<u8>;
+    for i in 0..size {
+        let byte = ptr::read(unerased.as_ptr().cast::<MaybeUninit<u8>>().add(i));
+        ptr::write(again.as_ptr().cast::<MaybeUninit<u8>>().add(i), byte);
+        let _ = ptr::read(bytes.add(i));
+    }
+}
@@ -0,0 +1,298 @@
+// NB: All impls are in rustdoc gutter order.
+
+use {
+    crate::{Erasable, ErasablePtr, ErasedPtr},
+    core::{
+        fmt::{self, Debug, Display, Pointer},
+        hash::{Hash, Hasher},
+        marker::PhantomData,
+        ops::{Deref, DerefMut},
+    },
+};
+
+/// A thin shared reference to an [`Erasable`] type.
+///
+/// This is a borrowed equivalent of `Thin<&'a T>` that is `Copy` and
+/// doesn't need to be dropped, so it works with normal borrow shortening.
+///
+/// This type is guaranteed to have the same repr as [`ErasedPtr`].
+///
+/// # Examples
+///
+/// ```rust
+/// use erasable::ThinRef;
+///
+/// let value = 5u32;
+/// let thin: ThinRef<'_, u32> = (&value).into();
+/// assert_eq!(*thin, 5);
+/// let fat: &u32 = ThinRef::into_ref(thin);
+/// assert!(std::ptr::eq(fat, &value));
+/// ```
+#[repr(transparent)]
+pub struct ThinRef<'a, T: ?Sized + Erasable> {
+    ptr: ErasedPtr,
+    marker: PhantomData<&'a T>,
+}
+
+unsafe impl<T: ?Sized + Erasable> Send for ThinRef<'_, T> where T: Sync {}
+unsafe impl<T: ?Sized + Erasable> Sync for ThinRef<'_, T> where T: Sync {}
+
+impl<'a, T: ?Sized + Erasable> From<&'a T> for ThinRef<'a, T> {
+    #[inline(always)]
+    fn from(this: &'a T) -> Self {
+        ThinRef {
+            ptr: ErasablePtr::erase(this),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized + Erasable> From<ThinMut<'a, T>> for ThinRef<'a, T> {
+    #[inline(always)]
+    fn from(this: ThinMut<'a, T>) -> Self {
+        ThinRef {
+            ptr: this.ptr,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized + Erasable> ThinRef<'a, T> {
+    // noinspection RsSelfConvention
+    /// Convert back into a normal (fat) reference.
+    pub fn into_ref(this: Self) -> &'a T {
+        unsafe { ErasablePtr::unerase(this.ptr) }
+    }
+
+    /// Check two thin references for pointer equivalence.
+    pub fn ptr_eq<U: ?Sized + Erasable>(this: Self, that: ThinRef<'_, U>) -> bool {
+        this.ptr == that.ptr
+    }
+}
+
+/// A thin unique reference to an [`Erasable`] type.
+///
+/// This is a borrowed equivalent of `Thin<&'a mut T>` that
+/// doesn't need to be dropped, so it works with normal borrow shortening.
+///
+/// This type is guaranteed to have the same repr as [`ErasedPtr`].
+///
+/// # Examples
+///
+/// ```rust
+/// use erasable::ThinMut;
+///
+/// let mut value = 5u32;
+/// let mut thin: ThinMut<'_, u32> = (&mut value).into();
+/// *thin += 1;
+/// let fat: &mut u32 = ThinMut::into_mut(thin);
+/// *fat += 1;
+/// assert_eq!(value, 7);
+/// ```
+#[repr(transparent)]
+pub struct ThinMut<'a, T: ?Sized + Erasable> {
+    ptr: ErasedPtr,
+    marker: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: ?Sized + Erasable> Send for ThinMut<'_, T> where T: Send {}
+unsafe impl<T: ?Sized + Erasable> Sync for ThinMut<'_, T> where T: Sync {}
+
+impl<'a, T: ?Sized + Erasable> From<&'a mut T> for ThinMut<'a, T> {
+    #[inline(always)]
+    fn from(this: &'a mut T) -> Self {
+        ThinMut {
+            ptr: ErasablePtr::erase(this),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized + Erasable> ThinMut<'a, T> {
+    // noinspection RsSelfConvention
+    /// Convert back into a normal (fat) reference.
+    pub fn into_mut(this: Self) -> &'a mut T {
+        unsafe { ErasablePtr::unerase(this.ptr) }
+    }
+
+    /// Reborrow this thin reference for a shorter lifetime.
+    pub fn reborrow(this: &mut Self) -> ThinMut<'_, T> {
+        ThinMut {
+            ptr: this.ptr,
+            marker: PhantomData,
+        }
+    }
+
+    /// Check two thin references for pointer equivalence.
+    pub fn ptr_eq<U: ?Sized + Erasable>(this: &Self, that: &ThinMut<'_, U>) -> bool {
+        this.ptr == that.ptr
+    }
+}
+
+// ~~~ &T like impls ~~~ //
+
+impl<T: ?Sized + Erasable> AsRef<T> for ThinRef<'_, T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized + Erasable> Clone for ThinRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized + Erasable> Copy for ThinRef<'_, T> {}
+
+impl<T: ?Sized + Erasable> Debug for ThinRef<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized + Erasable> Deref for ThinRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*T::unerase(self.ptr).as_ptr() }
+    }
+}
+
+impl<T: ?Sized + Erasable> Display for ThinRef<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized + Erasable> Eq for ThinRef<'_, T> where T: Eq {}
+
+impl<T: ?Sized + Erasable> Hash for ThinRef<'_, T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T: ?Sized + Erasable> PartialEq for ThinRef<'_, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: ?Sized + Erasable> Pointer for ThinRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Pointer::fmt(&self.ptr, f)
+    }
+}
+
+// ~~~ &mut T like impls ~~~ //
+
+impl<T: ?Sized + Erasable> AsMut<T> for ThinMut<'_, T> {
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: ?Sized + Erasable> AsRef<T> for ThinMut<'_, T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized + Erasable> Debug for ThinMut<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized + Erasable> Deref for ThinMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*T::unerase(self.ptr).as_ptr() }
+    }
+}
+
+impl<T: ?Sized + Erasable> DerefMut for ThinMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *T::unerase(self.ptr).as_ptr() }
+    }
+}
+
+impl<T: ?Sized + Erasable> Display for ThinMut<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized + Erasable> Eq for ThinMut<'_, T> where T: Eq {}
+
+impl<T: ?Sized + Erasable> Hash for ThinMut<'_, T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T: ?Sized + Erasable> PartialEq for ThinMut<'_, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: ?Sized + Erasable> Pointer for ThinMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Pointer::fmt(&self.ptr, f)
+    }
+}
+
+// ~~~ impl ErasablePtr ~~~ //
+
+unsafe impl<T: ?Sized + Erasable> ErasablePtr for ThinRef<'_, T> {
+    fn erase(this: Self) -> ErasedPtr {
+        this.ptr
+    }
+
+    unsafe fn unerase(this: ErasedPtr) -> Self {
+        ThinRef {
+            ptr: this,
+            marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<T: ?Sized + Erasable> ErasablePtr for ThinMut<'_, T> {
+    fn erase(this: Self) -> ErasedPtr {
+        this.ptr
+    }
+
+    unsafe fn unerase(this: ErasedPtr) -> Self {
+        ThinMut {
+            ptr: this,
+            marker: PhantomData,
+        }
+    }
+}
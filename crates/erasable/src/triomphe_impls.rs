@@ -0,0 +1,85 @@
+//! Interop with [`triomphe`]'s reference counted pointers.
+
+use {
+    crate::{Erasable, ErasablePtr, ErasedPtr, Thin},
+    core::{alloc::Layout, ptr},
+    triomphe::{Arc, HeaderSlice, HeaderWithLength, ThinArc, UniqueArc},
+};
+
+unsafe impl<T: ?Sized> ErasablePtr for Arc<T>
+where
+    T: Erasable,
+{
+    #[inline]
+    fn erase(this: Self) -> ErasedPtr {
+        let ptr = unsafe { ptr::NonNull::new_unchecked(Arc::into_raw(this) as *mut _) };
+        T::erase(ptr)
+    }
+
+    #[inline]
+    unsafe fn unerase(this: ErasedPtr) -> Self {
+        Arc::from_raw(T::unerase(this).as_ptr())
+    }
+}
+
+unsafe impl<T: ?Sized> ErasablePtr for UniqueArc<T>
+where
+    T: Erasable,
+{
+    #[inline]
+    fn erase(this: Self) -> ErasedPtr {
+        ErasablePtr::erase(this.shareable())
+    }
+
+    #[inline]
+    unsafe fn unerase(this: ErasedPtr) -> Self {
+        // NB: the erased pointer came from a unique arc, so this can't fail
+        Arc::try_unique(ErasablePtr::unerase(this))
+            .unwrap_or_else(|_| core::hint::unreachable_unchecked())
+    }
+}
+
+// SAFETY: ThinArc is already a thin pointer; the erased pointer is the raw pointer.
+unsafe impl<H, T> ErasablePtr for ThinArc<H, T> {
+    #[inline]
+    fn erase(this: Self) -> ErasedPtr {
+        unsafe { ptr::NonNull::new_unchecked(ThinArc::into_raw(this) as *mut _) }
+    }
+
+    #[inline]
+    unsafe fn unerase(this: ErasedPtr) -> Self {
+        ThinArc::from_raw(this.as_ptr() as *const _)
+    }
+}
+
+// SAFETY: `HeaderSlice` and `HeaderWithLength` are both `repr(C)`, so the length
+// is at a known offset from the start of the pointee, and we can read it raw.
+unsafe impl<H, T> Erasable for HeaderSlice<HeaderWithLength<H>, [T]> {
+    unsafe fn unerase(this: ErasedPtr) -> ptr::NonNull<Self> {
+        let (_, len_offset) = Layout::new::<H>()
+            .extend(Layout::new::<usize>())
+            .unwrap_or_else(|_| core::hint::unreachable_unchecked());
+        let len: usize = ptr::read(this.as_ptr().cast::<u8>().add(len_offset).cast());
+        let raw = crate::unerase_slice::<T>(this, len);
+        ptr::NonNull::new_unchecked(raw.as_ptr() as *mut Self)
+    }
+
+    fn unerase_dangling(this: ErasedPtr) -> Option<ptr::NonNull<Self>> {
+        let raw = crate::unerase_slice::<T>(this, 0);
+        Some(unsafe { ptr::NonNull::new_unchecked(raw.as_ptr() as *mut Self) })
+    }
+
+    const ACK_1_1_0: bool = true;
+}
+
+impl<H, T> From<ThinArc<H, T>> for Thin<Arc<HeaderSlice<HeaderWithLength<H>, [T]>>> {
+    fn from(this: ThinArc<H, T>) -> Self {
+        Arc::from_thin(this).into()
+    }
+}
+
+impl<H, T> From<Thin<Arc<HeaderSlice<HeaderWithLength<H>, [T]>>>> for ThinArc<H, T> {
+    fn from(this: Thin<Arc<HeaderSlice<HeaderWithLength<H>, [T]>>>) -> Self {
+        Arc::into_thin(Thin::into_inner(this))
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error},
+    boxed::Box,
+};
+use {
+    crate::{read_prefix, Erasable, ErasedPtr},
+    core::{
+        alloc::Layout,
+        any::Any,
+        error::Error,
+        fmt::{self, Debug, Display},
+        ops::{Deref, DerefMut},
+        ptr::{self, Pointee},
+    },
+};
+
+/// A wrapper that makes unsized types erasable by storing their pointer metadata inline.
+///
+/// This lets you use thin pointers to unsized types without bespoke DSTs, such as
+/// `Thin<Box<WithMetadata<dyn Trait>>>` or `Thin<Arc<WithMetadata<[T]>>>`,
+/// at the cost of storing the metadata in the allocation.
+///
+/// Because of the blanket `Erasable` impl for sized types, `WithMetadata<T>`
+/// can't implement `Erasable` for every unsized `T`. Instead, it is implemented
+/// for slices, `str`, and trait objects of the `core` traits `Any`, `Debug`,
+/// `Display`, `Error`, `Fn(A) -> R`, and `FnMut(A) -> R` (with `Send`/`Sync`).
+/// Other crates can't add implementations for their own trait objects,
+/// as both `Erasable` and `WithMetadata` are foreign to them.
+///
+/// Unsized `WithMetadata` can't be created by unsizing coercion, as that would
+/// change the type of the stored metadata. Instead, create it with
+/// [`WithMetadata::boxed`] and convert the box into the pointer type you want.
+///
+/// # Examples
+///
+/// ```rust
+/// use {erasable::{Thin, WithMetadata}, std::{fmt::Display, sync::Arc}};
+///
+/// let boxed: Box<dyn Display> = Box::new(42);
+/// let thin: Thin<Box<WithMetadata<dyn Display>>> = WithMetadata::boxed(boxed).into();
+/// assert_eq!(thin.to_string(), "42");
+///
+/// let boxed: Box<[u8]> = Box::new([1, 2, 3]);
+/// let arc: Arc<WithMetadata<[u8]>> = Arc::from(WithMetadata::boxed(boxed));
+/// let thin: Thin<Arc<_>> = arc.into();
+/// assert_eq!(**thin, [1, 2, 3]);
+/// ```
+#[repr(C)]
+pub struct WithMetadata<T: ?Sized> {
+    metadata: <T as Pointee>::Metadata,
+    value: T,
+}
+
+impl<T> WithMetadata<T> {
+    /// Wrap a sized value.
+    pub fn new(value: T) -> Self {
+        WithMetadata {
+            metadata: (),
+            value,
+        }
+    }
+
+    /// Unwrap a sized value.
+    pub fn into_inner(this: Self) -> T {
+        this.value
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> WithMetadata<T> {
+    /// Move a boxed value into a new allocation with its metadata stored inline.
+    #[allow(clippy::incompatible_msrv)] // gated on ptr_metadata, which requires nightly
+    pub fn boxed(value: Box<T>) -> Box<Self> {
+        let metadata = ptr::metadata(&*value);
+        let value_layout = Layout::for_value::<T>(&value);
+        let (layout, offset) = Layout::new::<<T as Pointee>::Metadata>()
+            .extend(value_layout)
+            .expect("WithMetadata layout overflow");
+        let layout = layout.pad_to_align();
+        unsafe {
+            let raw = if layout.size() == 0 {
+                // Do not allocate in the ZST case! CAD97/pointer-utils#23
+                ptr::without_provenance_mut(layout.align())
+            } else {
+                alloc(layout)
+            };
+            if raw.is_null() {
+                handle_alloc_error(layout)
+            }
+            let value = Box::into_raw(value);
+            ptr::write(raw.cast(), metadata);
+            ptr::copy_nonoverlapping(value.cast::<u8>(), raw.add(offset), value_layout.size());
+            if value_layout.size() != 0 {
+                dealloc(value.cast(), value_layout);
+            }
+            Box::from_raw(ptr::from_raw_parts_mut(raw, metadata))
+        }
+    }
+}
+
+impl<T: ?Sized> Debug for WithMetadata<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: ?Sized> Deref for WithMetadata<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: ?Sized> DerefMut for WithMetadata<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+// NB: `impl<T: ?Sized> Erasable for WithMetadata<T>` overlaps with `impl<T: Sized> Erasable for T`,
+// so we have to impl for concrete unsized types that are known to not be `Sized`.
+macro_rules! impl_erasable {
+    ($(for<$($T:ident),*> $ty:ty;)*) => {$(
+        unsafe impl<$($T),*> Erasable for WithMetadata<$ty> {
+            unsafe fn unerase(this: ErasedPtr) -> ptr::NonNull<Self> {
+                let metadata: <$ty as Pointee>::Metadata = read_prefix(this);
+                ptr::NonNull::from_raw_parts(this, metadata)
+            }
+
+            const ACK_1_1_0: bool = true;
+        }
+    )*};
+}
+
+impl_erasable! {
+    for<T> [T];
+    for<> str;
+    for<> dyn Any;
+    for<> dyn Any + Send;
+    for<> dyn Any + Send + Sync;
+    for<> dyn Debug;
+    for<> dyn Debug + Send;
+    for<> dyn Debug + Send + Sync;
+    for<> dyn Display;
+    for<> dyn Display + Send;
+    for<> dyn Display + Send + Sync;
+    for<> dyn Error;
+    for<> dyn Error + Send;
+    for<> dyn Error + Send + Sync;
+    for<A, R> dyn Fn(A) -> R;
+    for<A, R> dyn Fn(A) -> R + Send;
+    for<A, R> dyn Fn(A) -> R + Send + Sync;
+    for<A, R> dyn FnMut(A) -> R;
+    for<A, R> dyn FnMut(A) -> R + Send;
+    for<A, R> dyn FnMut(A) -> R + Send + Sync;
+}
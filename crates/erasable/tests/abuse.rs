@@ -1,7 +1,10 @@
 use {
     either::{Either, Left, Right},
-    erasable::{ErasablePtr, ErasedPtr, Thin},
-    std::ops::{Deref, DerefMut},
+    erasable::{CheckedThin, ErasablePtr, ErasedPtr, Thin},
+    std::{
+        ops::{Deref, DerefMut},
+        ptr::NonNull,
+    },
 };
 
 struct MeanestDerefInTheWest {
@@ -36,6 +39,7 @@ impl Deref for MeanestDerefInTheWest {
 }
 
 // this is the interesting/evil bit
+#[allow(unknown_lints, clippy::replace_box)] // the point is to change the pointer
 impl DerefMut for MeanestDerefInTheWest {
     fn deref_mut(&mut self) -> &mut usize {
         let val = **self;
@@ -62,3 +66,30 @@ fn panic_with_mut() {
     let mut b: Thin<Box<u8>> = Box::new(0).into();
     std::panic::catch_unwind(move || Thin::with_mut(&mut b, |_| panic!())).unwrap_err();
 }
+
+#[test]
+fn checked_meanest_deref() {
+    let mut mean = CheckedThin::from(MeanestDerefInTheWest::new());
+    for _ in 0..10 {
+        mean.deref_mut();
+    }
+    assert_eq!(*mean, 10);
+}
+
+struct OffByOne(NonNull<u8>);
+
+// NB: this impl is wrong on purpose
+unsafe impl ErasablePtr for OffByOne {
+    fn erase(this: Self) -> ErasedPtr {
+        unsafe { NonNull::new_unchecked(this.0.as_ptr().wrapping_add(1)).cast() }
+    }
+    unsafe fn unerase(this: ErasedPtr) -> Self {
+        OffByOne(this.cast())
+    }
+}
+
+#[test]
+#[should_panic = "ErasablePtr impl did not roundtrip"]
+fn checked_off_by_one() {
+    let _ = CheckedThin::from(OffByOne(NonNull::dangling()));
+}
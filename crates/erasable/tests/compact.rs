@@ -0,0 +1,60 @@
+use {
+    erasable::compact::{CompactBase, CompactThin},
+    std::{mem, ptr::NonNull},
+};
+
+#[test]
+fn roundtrip() {
+    let mut arena = [[0u32; 2]; 16];
+    let start = NonNull::from(&mut arena[..]).cast::<[u32; 2]>();
+    let base = CompactBase::new(start.cast(), 16 * 8, 4);
+
+    let edges: Vec<CompactThin<&mut [u32; 2]>> = (0..16)
+        .map(|i| CompactThin::new(&base, unsafe { &mut *start.as_ptr().add(i) }).unwrap())
+        .collect();
+    assert_eq!(mem::size_of::<Option<CompactThin<&mut [u32; 2]>>>(), 4);
+
+    for (i, edge) in edges.into_iter().enumerate() {
+        let node = unsafe { CompactThin::into_inner(edge, &base) };
+        node[1] = i as u32;
+    }
+    assert!(arena
+        .iter()
+        .enumerate()
+        .all(|(i, node)| node[1] == i as u32));
+}
+
+#[test]
+fn out_of_region() {
+    let mut arena = [0u32; 4];
+    let base = CompactBase::new(NonNull::from(&mut arena).cast(), 16, 4);
+
+    let outside = Box::new(0u32);
+    drop(CompactThin::new(&base, outside).unwrap_err());
+
+    let bytes = NonNull::from(&mut arena).cast::<u8>();
+    let misaligned = unsafe { bytes.as_ptr().add(1) };
+    assert_eq!(
+        base.compress(NonNull::new(misaligned).unwrap().cast()),
+        None
+    );
+}
+
+#[test]
+#[should_panic = "region is too long"]
+fn too_long() {
+    CompactBase::new(NonNull::dangling(), usize::MAX / 2, 1);
+}
+
+#[test]
+fn decompress_other_base() {
+    let mut big = [0u32; 64];
+    let mut small = [0u32; 4];
+    let big_base = CompactBase::new(NonNull::from(&mut big).cast(), 256, 4);
+    let small_base = CompactBase::new(NonNull::from(&mut small).cast(), 16, 4);
+    let ptr = big_base
+        .compress(NonNull::from(&mut big[32]).cast())
+        .unwrap();
+    assert!(big_base.decompress(ptr).is_some());
+    assert!(small_base.decompress(ptr).is_none());
+}
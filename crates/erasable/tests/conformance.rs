@@ -0,0 +1,25 @@
+//! Run the conformance checks over the provided implementations.
+//! This is primarily intended to be run under miri as a sanitizer.
+
+use {
+    erasable::{testing::*, Thin},
+    std::{pin::Pin, ptr, rc::Rc, sync::Arc},
+};
+
+#[test]
+fn erasable_ptr_impls() {
+    assert_erasable_ptr_deref(|| Box::new(0u64));
+    assert_erasable_ptr_deref(|| Arc::new(0u64));
+    assert_erasable_ptr_deref(|| Rc::new(0u64));
+    assert_erasable_ptr_deref(|| &0u64);
+    assert_erasable_ptr_deref(|| Box::pin(0u64));
+    assert_erasable_ptr_deref(|| Thin::from(Box::new(0u64)));
+    assert_erasable_ptr_roundtrip(|| Arc::downgrade(&Arc::new(0u64)));
+    assert_erasable_ptr_roundtrip(|| Pin::new(Box::new(())));
+}
+
+#[test]
+fn erasable_impls() {
+    let mut value = [0u8; 16];
+    unsafe { assert_erasable_roundtrip(ptr::NonNull::from(&mut value)) };
+}
@@ -0,0 +1,21 @@
+use {
+    erasable::{ErasablePtr, Thin},
+    std::{ffi::CStr, mem::size_of},
+};
+
+#[test]
+fn thin_cstr() {
+    let boxed: Box<CStr> = c"hello, world".into();
+    let thin: Thin<Box<CStr>> = boxed.into();
+    assert_eq!(size_of::<Thin<Box<CStr>>>(), size_of::<usize>());
+    assert_eq!(&*thin, c"hello, world");
+    assert_eq!(thin.to_bytes_with_nul().len(), 13);
+}
+
+#[test]
+fn empty_cstr() {
+    let empty: &CStr = c"";
+    let erased = ErasablePtr::erase(empty);
+    let unerased: &CStr = unsafe { ErasablePtr::unerase(erased) };
+    assert_eq!(unerased.to_bytes_with_nul(), b"\0");
+}
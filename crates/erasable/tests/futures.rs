@@ -0,0 +1,46 @@
+use {
+    erasable::Thin,
+    futures_core::Stream,
+    std::{
+        pin::Pin,
+        ptr,
+        task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    },
+};
+
+struct Countdown(u32);
+
+impl Stream for Countdown {
+    type Item = u32;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.0 == 0 {
+            Poll::Ready(None)
+        } else {
+            self.0 -= 1;
+            Poll::Ready(Some(self.0))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0 as usize, Some(self.0 as usize))
+    }
+}
+
+fn noop_waker() -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| {}, |_| {}, |_| {});
+    const RAW: RawWaker = RawWaker::new(ptr::null(), &VTABLE);
+    unsafe { Waker::from_raw(RAW) }
+}
+
+#[test]
+fn stream() {
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut thin: Thin<Pin<Box<Countdown>>> = Box::pin(Countdown(2)).into();
+    assert_eq!(thin.size_hint(), (2, Some(2)));
+    let mut thin = Pin::new(&mut thin);
+    assert_eq!(thin.as_mut().poll_next(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(thin.as_mut().poll_next(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(thin.as_mut().poll_next(&mut cx), Poll::Ready(None));
+}
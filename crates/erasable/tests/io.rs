@@ -0,0 +1,32 @@
+use {
+    erasable::Thin,
+    std::io::{BufRead, BufReader, Cursor, Read, Seek, SeekFrom, Write},
+};
+
+#[test]
+fn read_seek() {
+    let mut thin: Thin<Box<Cursor<Vec<u8>>>> = Box::new(Cursor::new(b"hello".to_vec())).into();
+    let mut buf = String::new();
+    thin.read_to_string(&mut buf).unwrap();
+    assert_eq!(buf, "hello");
+    assert_eq!(thin.seek(SeekFrom::Start(1)).unwrap(), 1);
+    let mut buf = [0; 2];
+    thin.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"el");
+}
+
+#[test]
+fn write() {
+    let mut thin: Thin<Box<Vec<u8>>> = Box::new(Vec::new()).into();
+    write!(thin, "hello, {}", 42).unwrap();
+    thin.flush().unwrap();
+    assert_eq!(&**thin, b"hello, 42");
+}
+
+#[test]
+fn buf_read() {
+    let reader = BufReader::new(&b"one\ntwo\n"[..]);
+    let thin: Thin<Box<_>> = Box::new(reader).into();
+    let lines: Vec<String> = thin.lines().map(Result::unwrap).collect();
+    assert_eq!(lines, ["one", "two"]);
+}
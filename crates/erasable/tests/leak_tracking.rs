@@ -0,0 +1,33 @@
+use {
+    erasable::{leak_tracking::live_thin_count, ErasablePtr, Thin},
+    std::{mem, sync::Arc},
+};
+
+// NB: this is a single test, as the count is global and tests run in parallel
+#[allow(unknown_lints, clippy::replace_box)] // the point is to change the pointer
+#[test]
+fn live_count() {
+    let base = live_thin_count();
+
+    let mut a: Thin<Box<u32>> = Box::new(0).into();
+    let b: Thin<Arc<u32>> = Arc::new(0).into();
+    let c = b.clone();
+    assert_eq!(live_thin_count(), base + 3);
+
+    Thin::with_mut(&mut a, |a| *a = Box::new(1));
+    assert_eq!(live_thin_count(), base + 3);
+
+    let erased = ErasablePtr::erase(c);
+    assert_eq!(live_thin_count(), base + 3);
+    drop(unsafe { <Thin<Arc<u32>> as ErasablePtr>::unerase(erased) });
+    assert_eq!(live_thin_count(), base + 2);
+
+    drop(Thin::into_inner(a));
+    assert_eq!(live_thin_count(), base + 1);
+
+    // a forgotten pointer is still live, which is what this is for
+    let b = mem::ManuallyDrop::new(b);
+    assert_eq!(live_thin_count(), base + 1);
+    drop(mem::ManuallyDrop::into_inner(b));
+    assert_eq!(live_thin_count(), base);
+}
@@ -0,0 +1,24 @@
+use {
+    erasable::Thin,
+    serde_test::{assert_tokens, Token},
+};
+
+#[test]
+fn thin_box() {
+    let thin: Thin<Box<u32>> = Box::new(5).into();
+    assert_tokens(&thin, &[Token::U32(5)]);
+}
+
+#[test]
+fn thin_box_tuple() {
+    let thin: Thin<Box<(u8, bool)>> = Box::new((1, true)).into();
+    assert_tokens(
+        &thin,
+        &[
+            Token::Tuple { len: 2 },
+            Token::U8(1),
+            Token::Bool(true),
+            Token::TupleEnd,
+        ],
+    );
+}
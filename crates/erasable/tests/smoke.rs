@@ -1,7 +1,7 @@
 //! These tests don't really assert anything, they just exercise the API.
 //! This is primarily intended to be run under miri as a sanitizer.
 
-#![allow(unused, clippy::style)]
+#![allow(unknown_lints, unused, clippy::style, clippy::replace_box)]
 
 use erasable::{Erasable, ErasablePtr, ErasedPtr, Thin};
 
@@ -32,3 +32,233 @@ fn thinning() {
     Thin::with_mut(&mut thin, |thin| *thin = Default::default());
     let boxed = Thin::into_inner(thin);
 }
+
+#[test]
+fn thin_refs() {
+    use erasable::{ThinMut, ThinRef};
+    let mut big = Big::default();
+    let mut thin_mut: ThinMut<'_, Big> = (&mut big).into();
+    thin_mut.0[0] = 1;
+    ThinMut::reborrow(&mut thin_mut).0[1] = 2;
+    let thin_ref: ThinRef<'_, Big> = thin_mut.into();
+    let copy = thin_ref;
+    assert_eq!(thin_ref, copy);
+    assert_eq!(ThinRef::into_ref(copy).0[..2], [1, 2]);
+    assert_eq!(big.0[..2], [1, 2]);
+}
+
+#[test]
+fn ffi() {
+    use std::{ffi::c_void, mem, ptr};
+    let null: Option<Thin<Box<Big>>> = unsafe { mem::transmute(ptr::null_mut::<c_void>()) };
+    assert!(null.is_none());
+    let thin: Thin<Box<Big>> = Box::new(Big::default()).into();
+    let void = Thin::into_ffi(thin);
+    let thin: Thin<Box<Big>> = unsafe { Thin::from_ffi(void) };
+}
+
+#[test]
+fn compare_unthinned() {
+    let thin: Thin<Box<Big>> = Box::new(Big::default()).into();
+    assert!(thin == Box::new(Big::default()));
+    assert!(thin <= Box::new(Big::default()));
+}
+
+#[test]
+fn default() {
+    #[derive(Default)]
+    struct Fields {
+        a: Thin<Box<Big>>,
+        b: Thin<Box<Big>>,
+    }
+    let fields = Fields::default();
+    assert!(*fields.a == Big::default());
+}
+
+#[test]
+fn erased_refs() {
+    let mut big = Big::default();
+    let expected = ErasablePtr::erase(&mut big);
+    let mut erased = erasable::erase_mut(&mut big);
+    unsafe { erased.reborrow().unerase::<Big>() }.0[0] = 1;
+    let erased: erasable::ErasedRef<'_> = erased.into();
+    assert_eq!(erased.as_ptr(), expected);
+    assert_eq!(unsafe { erased.unerase::<Big>() }.0[0], 1);
+}
+
+#[test]
+fn atomic() {
+    use {erasable::AtomicErasedPtr, std::sync::atomic::Ordering};
+    let a = ErasablePtr::erase(Box::new(Big::default()));
+    let b = ErasablePtr::erase(Box::new(Big::default()));
+    let mut cell = AtomicErasedPtr::new(a);
+    assert_eq!(
+        cell.compare_exchange(b, b, Ordering::AcqRel, Ordering::Acquire),
+        Err(a)
+    );
+    assert_eq!(
+        cell.compare_exchange(a, b, Ordering::AcqRel, Ordering::Acquire),
+        Ok(a)
+    );
+    assert_eq!(*cell.get_mut(), b);
+    cell.store(a, Ordering::Release);
+    assert_eq!(cell.load(Ordering::Acquire), a);
+    drop(unsafe { cell.into_erasable::<Box<Big>>() });
+    drop(unsafe { <Box<Big> as ErasablePtr>::unerase(b) });
+}
+
+#[test]
+fn make_mut() {
+    use std::{rc::Rc, sync::Arc};
+    let mut thin: Thin<Arc<Big>> = Arc::new(Big::default()).into();
+    let shared = thin.clone();
+    Thin::make_mut(&mut thin).0[0] = 1;
+    assert!(!Thin::ptr_eq(&thin, &shared));
+    assert_eq!((thin.0[0], shared.0[0]), (1, 0));
+    let mut thin: Thin<Rc<Big>> = Rc::new(Big::default()).into();
+    let before = thin.clone();
+    drop(before);
+    let ptr = &*thin as *const Big;
+    Thin::make_mut(&mut thin).0[0] = 1;
+    assert_eq!(&*thin as *const Big, ptr);
+}
+
+#[test]
+fn map() {
+    use std::{panic, rc::Rc};
+    let thin: Thin<Box<Big>> = Box::new(Big::default()).into();
+    let thin: Thin<Rc<Big>> = Thin::map(thin, Rc::from);
+    let weak = Rc::downgrade(&Thin::with(&thin, Rc::clone));
+    panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        Thin::map(thin, |_| -> Box<Big> { panic!() });
+    }))
+    .unwrap_err();
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn extend() {
+    #[allow(clippy::box_collection)] // Vec isn't ErasablePtr
+    struct VecBox(Box<Vec<u32>>);
+    unsafe impl ErasablePtr for VecBox {
+        fn erase(this: Self) -> ErasedPtr {
+            ErasablePtr::erase(this.0)
+        }
+        unsafe fn unerase(this: ErasedPtr) -> Self {
+            VecBox(ErasablePtr::unerase(this))
+        }
+    }
+    impl Extend<u32> for VecBox {
+        fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+            self.0.extend(iter)
+        }
+    }
+
+    let mut thin: Thin<VecBox> = VecBox(Box::new(vec![1])).into();
+    Extend::extend(&mut thin, [2, 3]);
+    assert_eq!(*Thin::into_inner(thin).0, [1, 2, 3]);
+}
+
+#[test]
+fn newtype() {
+    use std::sync::Arc;
+
+    struct Shared<T: ?Sized>(Arc<T>);
+    struct Pair<A, B>(Box<(A, B)>);
+
+    erasable::impl_erasable_for_newtype! {
+        unsafe impl<T: ?Sized> ErasablePtr for Shared<T>(Arc<T>);
+        unsafe impl<A, B> ErasablePtr for Pair<A, B>(Box<(A, B)>);
+    }
+
+    erasable::testing::assert_erasable_ptr_roundtrip(|| Shared(Arc::new(Big::default())));
+    erasable::testing::assert_erasable_ptr_roundtrip(|| Pair(Box::new((0u8, 1u32))));
+}
+
+#[test]
+fn markers() {
+    use std::{
+        cell::Cell,
+        marker::PhantomPinned,
+        panic::{RefUnwindSafe, UnwindSafe},
+        pin::Pin,
+    };
+    fn unpin<T: Unpin>() {}
+    fn unwind_safe<T: UnwindSafe>() {}
+    fn ref_unwind_safe<T: RefUnwindSafe>() {}
+
+    unpin::<Thin<Box<PhantomPinned>>>();
+    unpin::<Thin<Pin<Box<PhantomPinned>>>>();
+    unwind_safe::<Thin<Box<Big>>>();
+    unwind_safe::<Thin<&Big>>();
+    ref_unwind_safe::<Thin<Box<Big>>>();
+    ref_unwind_safe::<Thin<&Big>>();
+    unwind_safe::<Thin<Box<Cell<Big>>>>();
+}
+
+#[test]
+fn manually_dropped() {
+    use std::mem::ManuallyDrop;
+    let thin: ManuallyDrop<Thin<Box<Big>>> = ManuallyDrop::new(Box::new(Big::default()).into());
+    let copy: ManuallyDrop<Thin<Box<Big>>> = unsafe { std::ptr::read(&thin) };
+    assert!(Thin::ptr_eq(&thin, &copy));
+    let boxed = Thin::into_inner(ManuallyDrop::into_inner(copy));
+}
+
+#[test]
+#[cfg(any(has_core_error, feature = "std"))]
+fn error() {
+    use std::{error::Error, fmt};
+
+    #[derive(Debug)]
+    struct Inner;
+    impl fmt::Display for Inner {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("inner")
+        }
+    }
+    impl Error for Inner {}
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("outer")
+        }
+    }
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let thin: Thin<Box<Outer>> = Box::new(Outer(Inner)).into();
+    let err: &dyn Error = &thin;
+    assert_eq!(err.to_string(), "outer");
+    assert_eq!(err.source().unwrap().to_string(), "inner");
+}
+
+#[test]
+fn fmt_write() {
+    use std::fmt::Write;
+    let mut thin: Thin<Box<String>> = Box::new(String::new()).into();
+    write!(thin, "{}-{}", 1, 2).unwrap();
+    thin.write_char('!').unwrap();
+    assert_eq!(&**thin, "1-2!");
+}
+
+#[test]
+fn by_ptr() {
+    use {erasable::ByPtr, std::collections::BTreeSet, std::rc::Rc};
+
+    let a: Thin<Rc<Big>> = Rc::new(Big::default()).into();
+    let b: Thin<Rc<Big>> = Rc::new(Big::default()).into();
+    assert_eq!(*a, *b);
+    let a = ByPtr(a);
+    let b = ByPtr(b);
+    assert_ne!(a, b);
+    let mut set = BTreeSet::new();
+    set.insert(ByPtr(&*a));
+    assert!(set.contains(&ByPtr(&*a)));
+    assert!(!set.contains(&ByPtr(&*b)));
+}
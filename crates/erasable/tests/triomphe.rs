@@ -0,0 +1,37 @@
+use {
+    erasable::{ErasablePtr, Thin},
+    triomphe::{Arc, HeaderWithLength, ThinArc, UniqueArc},
+};
+
+#[test]
+fn arc_roundtrip() {
+    let arc = Arc::new(42u64);
+    let thin: Thin<Arc<u64>> = Arc::clone(&arc).into();
+    assert_eq!(*thin, 42);
+    assert_eq!(Arc::count(&arc), 2);
+    drop(thin);
+    assert_eq!(Arc::count(&arc), 1);
+}
+
+#[test]
+fn unique_arc_roundtrip() {
+    let mut thin: Thin<UniqueArc<u64>> = UniqueArc::new(0u64).into();
+    *thin += 1;
+    let unique = Thin::into_inner(thin);
+    assert_eq!(UniqueArc::into_inner(unique), 1);
+}
+
+#[test]
+fn thin_arc_conversions() {
+    let thin_arc = ThinArc::from_header_and_iter(7u8, vec![1u32, 2, 3].into_iter());
+    let erased = ErasablePtr::erase(thin_arc);
+    let thin_arc: ThinArc<u8, u32> = unsafe { ErasablePtr::unerase(erased) };
+    assert_eq!(thin_arc.slice, [1, 2, 3]);
+
+    let thin: Thin<Arc<_>> = thin_arc.into();
+    assert_eq!(thin.header, HeaderWithLength::new(7, 3));
+    assert_eq!(thin.slice, [1, 2, 3]);
+
+    let thin_arc: ThinArc<u8, u32> = thin.into();
+    assert_eq!(thin_arc.header.header, 7);
+}
@@ -0,0 +1,70 @@
+use {
+    erasable::{Erasable, ErasedPtr, Thin},
+    std::{ptr, rc, sync},
+};
+
+#[repr(C)]
+struct Dst<T: ?Sized> {
+    len: usize,
+    data: T,
+}
+
+unsafe impl Erasable for Dst<[u8]> {
+    unsafe fn unerase(this: ErasedPtr) -> ptr::NonNull<Self> {
+        let len: usize = erasable::read_prefix(this);
+        let raw = erasable::unerase_slice::<u8>(this, len);
+        ptr::NonNull::new_unchecked(raw.as_ptr() as *mut Self)
+    }
+
+    fn unerase_dangling(this: ErasedPtr) -> Option<ptr::NonNull<Self>> {
+        let raw = erasable::unerase_slice::<u8>(this, 0);
+        Some(unsafe { ptr::NonNull::new_unchecked(raw.as_ptr() as *mut Self) })
+    }
+
+    const ACK_1_1_0: bool = true;
+}
+
+#[test]
+fn dangling_sized() {
+    let weak: Thin<rc::Weak<u32>> = rc::Weak::new().into();
+    assert!(Thin::with(&weak, |weak| weak.upgrade().is_none()));
+    let weak: Thin<sync::Weak<u32>> = sync::Weak::new().into();
+    assert!(Thin::with(&weak, |weak| weak.upgrade().is_none()));
+}
+
+#[test]
+fn live_unsized() {
+    let rc: rc::Rc<Dst<[u8]>> = rc::Rc::new(Dst {
+        len: 3,
+        data: [1, 2, 3],
+    });
+    let weak: Thin<rc::Weak<Dst<[u8]>>> = rc::Rc::downgrade(&rc).into();
+    let weak = Thin::into_inner(weak);
+    assert_eq!(weak.upgrade().unwrap().data, [1, 2, 3]);
+}
+
+#[test]
+fn dangling_unsized_rc() {
+    let weak: rc::Weak<Dst<[u8]>> = rc::Weak::<Dst<[u8; 3]>>::new();
+    let weak: Thin<rc::Weak<Dst<[u8]>>> = weak.into();
+    assert!(Thin::with(&weak, |weak| weak.upgrade().is_none()));
+}
+
+#[test]
+fn dangling_unsized_arc() {
+    let weak: sync::Weak<Dst<[u8]>> = sync::Weak::<Dst<[u8; 3]>>::new();
+    let weak: Thin<sync::Weak<Dst<[u8]>>> = weak.into();
+    assert!(Thin::with(&weak, |weak| weak.upgrade().is_none()));
+}
+
+#[test]
+fn with_mut_to_dangling_unsized() {
+    let rc: rc::Rc<Dst<[u8]>> = rc::Rc::new(Dst {
+        len: 3,
+        data: [1, 2, 3],
+    });
+    let mut weak: Thin<rc::Weak<Dst<[u8]>>> = rc::Rc::downgrade(&rc).into();
+    Thin::with_mut(&mut weak, |weak| *weak = rc::Weak::<Dst<[u8; 3]>>::new());
+    assert_eq!(rc::Rc::weak_count(&rc), 0);
+    assert!(Thin::with(&weak, |weak| weak.upgrade().is_none()));
+}
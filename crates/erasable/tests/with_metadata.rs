@@ -0,0 +1,45 @@
+use {
+    erasable::{testing, Thin, WithMetadata},
+    std::{fmt::Debug, ptr::NonNull, rc::Rc, sync::Arc},
+};
+
+#[test]
+fn dyn_trait() {
+    let boxed: Box<dyn Debug> = Box::new(String::from("hello"));
+    let thin: Thin<Box<WithMetadata<dyn Debug>>> = WithMetadata::boxed(boxed).into();
+    assert_eq!(format!("{:?}", thin), "\"hello\"");
+}
+
+#[test]
+fn slice() {
+    let boxed: Box<[String]> = vec![String::from("a"), String::from("b")].into();
+    let rc: Rc<WithMetadata<[String]>> = Rc::from(WithMetadata::boxed(boxed));
+    let thin: Thin<Rc<_>> = rc.into();
+    let clone = thin.clone();
+    assert_eq!(**clone, ["a", "b"]);
+}
+
+#[test]
+fn zero_sized() {
+    let boxed: Box<[()]> = vec![(); 10].into();
+    let thin: Thin<Box<WithMetadata<[()]>>> = WithMetadata::boxed(boxed).into();
+    assert_eq!(thin.len(), 10);
+    let thin: Thin<Box<WithMetadata<()>>> = Box::new(WithMetadata::new(())).into();
+    assert_eq!(WithMetadata::into_inner(*Thin::into_inner(thin)), ());
+}
+
+#[test]
+fn boxed_zero_sized() {
+    let boxed: Box<WithMetadata<()>> = WithMetadata::boxed(Box::new(()));
+    assert_eq!(WithMetadata::into_inner(*boxed), ());
+}
+
+#[test]
+fn conformance() {
+    testing::assert_erasable_ptr_deref(|| {
+        let boxed: Box<[u32]> = vec![1, 2, 3].into();
+        Arc::<WithMetadata<[u32]>>::from(WithMetadata::boxed(boxed))
+    });
+    let mut boxed = WithMetadata::boxed(Box::<[u8]>::from(&b"bytes"[..]));
+    unsafe { testing::assert_erasable_roundtrip(NonNull::from(&mut *boxed)) };
+}
@@ -38,6 +38,7 @@ const TAG_N: usize = 0b1101;
 const TAG_O: usize = 0b1110;
 const TAG_P: usize = 0b1111;
 
+#[allow(clippy::incompatible_msrv)] // gated on has_strict_provenance
 fn ptr_addr<T>(this: *mut T) -> usize {
     #[cfg(not(has_strict_provenance))]
     {
@@ -64,6 +65,7 @@ fn ptr_with_addr<T>(this: *mut T, addr: usize) -> *mut T {
     this.cast::<u8>().wrapping_offset(offset).cast::<T>()
 }
 
+#[allow(clippy::incompatible_msrv)] // gated on has_strict_provenance
 fn ptr_map_addr<T>(this: *mut T, f: impl FnOnce(usize) -> usize) -> *mut T {
     #[cfg(not(has_strict_provenance))]
     {
@@ -12,6 +12,7 @@ struct MyBox {
     ptr: NonNull<u8>,
 }
 
+#[allow(clippy::incompatible_msrv)] // gated on has_strict_provenance
 fn ptr_dangling_at<T>(addr: usize) -> *mut T {
     #[cfg(not(has_strict_provenance))]
     {
@@ -0,0 +1,11 @@
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(has_core_error)");
+
+    let cfg = autocfg::new();
+
+    if cfg.probe_rustc_version(1, 81) {
+        autocfg::emit("has_core_error");
+    }
+
+    autocfg::rerun_path("build.rs");
+}
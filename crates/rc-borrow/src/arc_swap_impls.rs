@@ -0,0 +1,46 @@
+use {
+    crate::ArcBorrow,
+    alloc::sync::Arc,
+    arc_swap::{strategy::Strategy, ArcSwapAny, Guard},
+};
+
+impl<'g, T, S> From<&'g Guard<Arc<T>, S>> for ArcBorrow<'g, T>
+where
+    S: Strategy<Arc<T>>,
+{
+    fn from(v: &'g Guard<Arc<T>, S>) -> Self {
+        (&**v).into()
+    }
+}
+
+/// Extension methods for borrowing out of an [`ArcSwapAny`].
+pub trait ArcSwapExt<T> {
+    /// Load the current value and run a closure with a borrow of it.
+    ///
+    /// The borrow can't outlive the closure, as the value may be
+    /// swapped out and dropped as soon as the load guard is released.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use {arc_swap::ArcSwap, rc_borrow::*};
+    /// let config = ArcSwap::from_pointee(42);
+    /// let value = config.with_borrow(|borrowed| *borrowed);
+    /// assert_eq!(value, 42);
+    /// ```
+    fn with_borrow<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(ArcBorrow<'_, T>) -> R;
+}
+
+impl<T, S> ArcSwapExt<T> for ArcSwapAny<Arc<T>, S>
+where
+    S: Strategy<Arc<T>>,
+{
+    fn with_borrow<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(ArcBorrow<'_, T>) -> R,
+    {
+        f((&self.load()).into())
+    }
+}
@@ -0,0 +1,114 @@
+use {
+    crate::{ArcBorrow, RcBorrow},
+    alloc::{
+        rc::{self, Rc},
+        sync::{self, Arc},
+    },
+};
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::ArcFamily {}
+    impl Sealed for super::RcFamily {}
+}
+
+/// A family of reference counted pointers, for code generic over `Rc` vs `Arc`.
+///
+/// This trait is sealed; it is implemented only for [`ArcFamily`] and [`RcFamily`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use {rc_borrow::*, std::{rc::Rc, sync::Arc}};
+/// fn first<F: RefCountFamily>(items: F::Borrow<'_, [u32]>) -> Option<u32> {
+///     F::downgrade(items).first().copied()
+/// }
+///
+/// let items: Arc<[u32]> = Arc::from(vec![1, 2, 3]);
+/// assert_eq!(first::<ArcFamily>(ArcFamily::borrow(&items)), Some(1));
+/// let items: Rc<[u32]> = Rc::from(vec![1, 2, 3]);
+/// assert_eq!(first::<RcFamily>(RcFamily::borrow(&items)), Some(1));
+/// ```
+pub trait RefCountFamily: sealed::Sealed {
+    /// The strong owning pointer, e.g. `Arc<T>`.
+    type Strong<T: ?Sized>;
+    /// The weak owning pointer, e.g. `sync::Weak<T>`.
+    type Weak<T: ?Sized>;
+    /// The borrowed pointer, e.g. `ArcBorrow<'a, T>`.
+    type Borrow<'a, T: ?Sized + 'a>: Copy;
+
+    /// Allocate a new strong pointer.
+    fn new<T>(value: T) -> Self::Strong<T>;
+
+    /// Borrow a strong pointer.
+    fn borrow<T: ?Sized>(this: &Self::Strong<T>) -> Self::Borrow<'_, T>;
+
+    /// Convert a borrowed pointer into an owned pointer.
+    fn upgrade<T: ?Sized>(this: Self::Borrow<'_, T>) -> Self::Strong<T>;
+
+    /// Convert a borrowed pointer into a weak pointer.
+    fn to_weak<T: ?Sized>(this: Self::Borrow<'_, T>) -> Self::Weak<T>;
+
+    /// Convert a borrowed pointer into a standard reference.
+    fn downgrade<T: ?Sized>(this: Self::Borrow<'_, T>) -> &T;
+}
+
+/// The [`Arc`] family of reference counted pointers.
+#[derive(Debug, Clone, Copy)]
+pub enum ArcFamily {}
+
+/// The [`Rc`] family of reference counted pointers.
+#[derive(Debug, Clone, Copy)]
+pub enum RcFamily {}
+
+impl RefCountFamily for ArcFamily {
+    type Strong<T: ?Sized> = Arc<T>;
+    type Weak<T: ?Sized> = sync::Weak<T>;
+    type Borrow<'a, T: ?Sized + 'a> = ArcBorrow<'a, T>;
+
+    fn new<T>(value: T) -> Self::Strong<T> {
+        Arc::new(value)
+    }
+
+    fn borrow<T: ?Sized>(this: &Self::Strong<T>) -> Self::Borrow<'_, T> {
+        this.into()
+    }
+
+    fn upgrade<T: ?Sized>(this: Self::Borrow<'_, T>) -> Self::Strong<T> {
+        ArcBorrow::upgrade(this)
+    }
+
+    fn to_weak<T: ?Sized>(this: Self::Borrow<'_, T>) -> Self::Weak<T> {
+        ArcBorrow::to_weak(this)
+    }
+
+    fn downgrade<T: ?Sized>(this: Self::Borrow<'_, T>) -> &T {
+        ArcBorrow::downgrade(this)
+    }
+}
+
+impl RefCountFamily for RcFamily {
+    type Strong<T: ?Sized> = Rc<T>;
+    type Weak<T: ?Sized> = rc::Weak<T>;
+    type Borrow<'a, T: ?Sized + 'a> = RcBorrow<'a, T>;
+
+    fn new<T>(value: T) -> Self::Strong<T> {
+        Rc::new(value)
+    }
+
+    fn borrow<T: ?Sized>(this: &Self::Strong<T>) -> Self::Borrow<'_, T> {
+        this.into()
+    }
+
+    fn upgrade<T: ?Sized>(this: Self::Borrow<'_, T>) -> Self::Strong<T> {
+        RcBorrow::upgrade(this)
+    }
+
+    fn to_weak<T: ?Sized>(this: Self::Borrow<'_, T>) -> Self::Weak<T> {
+        RcBorrow::to_weak(this)
+    }
+
+    fn downgrade<T: ?Sized>(this: Self::Borrow<'_, T>) -> &T {
+        RcBorrow::downgrade(this)
+    }
+}
@@ -26,8 +26,20 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod family;
+pub use family::{ArcFamily, RcFamily, RefCountFamily};
+
+#[cfg(feature = "arc-swap")]
+mod arc_swap_impls;
+#[cfg(feature = "arc-swap")]
+pub use arc_swap_impls::ArcSwapExt;
+
+#[cfg(has_core_error)]
+use core::error::Error;
 #[cfg(feature = "erasable")]
-use erasable::{Erasable, ErasablePtr, ErasedPtr};
+use erasable::{Erasable, ErasablePtr, ErasedPtr, Thin};
+#[cfg(all(not(has_core_error), feature = "std"))]
+use std::error::Error;
 #[cfg(feature = "std")]
 use std::{
     io,
@@ -50,6 +62,7 @@ use {
         marker::PhantomData,
         mem::ManuallyDrop,
         ops::Deref,
+        pin::Pin,
         ptr,
     },
 };
@@ -122,7 +135,7 @@ macro_rules! doc_comment {
 }
 
 macro_rules! rc_borrow {
-    ($($(#[$m:meta])* $vis:vis struct $RcBorrow:ident = &$rc:ident::$Rc:ident;)*) => {$(
+    ($($(#[$m:meta])* $vis:vis struct $RcBorrow:ident = &$rc:ident::$Rc:ident { $ptr_eq_rc:ident };)*) => {$(
         $(#[$m])*
         $vis struct $RcBorrow<'a, T: ?Sized> {
             raw: ptr::NonNull<T>,
@@ -143,13 +156,36 @@ macro_rules! rc_borrow {
             }
         }
 
+        // NB: this pins the pointer, not the pointee, and the pointer is Unpin.
+        impl<'a, T: ?Sized> From<Pin<&'a $Rc<T>>> for $RcBorrow<'a, T> {
+            fn from(v: Pin<&'a $Rc<T>>) -> $RcBorrow<'a, T> {
+                v.get_ref().into()
+            }
+        }
+
+        impl<'a, T: ?Sized> From<&'a Pin<$Rc<T>>> for Pin<$RcBorrow<'a, T>> {
+            fn from(v: &'a Pin<$Rc<T>>) -> Pin<$RcBorrow<'a, T>> {
+                // SAFETY: Pin is repr(transparent), and the pointee stays pinned,
+                // as a borrowed pointer never gives out mutable access to it
+                let v = unsafe { &*(v as *const Pin<$Rc<T>> as *const $Rc<T>) };
+                unsafe { Pin::new_unchecked(v.into()) }
+            }
+        }
+
         impl<'a, T: ?Sized> $RcBorrow<'a, T> {
             /// Convert this borrowed pointer into an owned pointer.
             $vis fn upgrade(this: Self) -> $Rc<T> {
                 unsafe { <$Rc<T> as RawRc<T>>::clone_raw(this.raw.as_ptr()) }
             }
 
+            /// Convert this pinned borrowed pointer into a pinned owned pointer.
+            $vis fn upgrade_pin(this: Pin<Self>) -> Pin<$Rc<T>> {
+                unsafe { Pin::new_unchecked(Self::upgrade(Pin::into_inner_unchecked(this))) }
+            }
+
             /// Convert this borrowed pointer into a weak pointer.
+            ///
+            /// This only increments the weak count; the strong count is untouched.
             $vis fn to_weak(this: Self) -> $rc::Weak<T> {
                 unsafe { <$Rc<T> as RawRc<T>>::downgrade_raw(this.raw.as_ptr()) }
             }
@@ -174,6 +210,35 @@ macro_rules! rc_borrow {
                 $Rc::weak_count(&rc)
             }
 
+            /// Returns `true` if the two borrowed pointers point to the same allocation.
+            ///
+            /// Like [`ptr::addr_eq`], this ignores any pointer metadata.
+            $vis fn ptr_eq(this: Self, other: $RcBorrow<'_, T>) -> bool {
+                ptr::addr_eq(this.raw.as_ptr(), other.raw.as_ptr())
+            }
+
+            doc_comment! {
+                concat!("\
+Returns `true` if the borrowed pointer points to the same allocation as the `", stringify!($Rc), "`.
+
+Like [`ptr::addr_eq`], this ignores any pointer metadata."),
+                $vis fn $ptr_eq_rc(this: Self, other: &$Rc<T>) -> bool {
+                    ptr::addr_eq(this.raw.as_ptr(), $Rc::as_ptr(other))
+                }
+            }
+
+            /// Get the address of the allocation's value, for logging or as a key.
+            ///
+            /// This ignores any pointer metadata.
+            $vis fn addr(this: Self) -> usize {
+                this.raw.as_ptr() as *const u8 as usize
+            }
+
+            /// Get the pointer to the allocation's value without consuming the borrow.
+            $vis fn as_non_null(this: Self) -> ptr::NonNull<T> {
+                this.raw
+            }
+
             /// Get a raw pointer that can be used with `from_raw`.
             $vis fn into_raw(this: Self) -> *const T {
                 ManuallyDrop::new(this).raw.as_ptr()
@@ -197,6 +262,70 @@ between the two types, and the types must be transmute-compatible."),
                     }
                 }
             }
+
+            doc_comment! {
+                concat!("\
+Construct a new `", stringify!($RcBorrow), "` from a reference.
+
+# Safety
+
+The reference must point to the value in a live `", stringify!($Rc), "<T>` allocation,
+such as one previously obtained by dereferencing a `", stringify!($Rc), "<T>`,
+and that allocation must be kept alive for the lifetime `'a`."),
+                $vis unsafe fn from_ref(r: &'a T) -> Self {
+                    Self::from_raw(r)
+                }
+            }
+
+            /// Get a raw pointer that can be used with `from_raw_opt`.
+            ///
+            /// `None` is represented by a null pointer.
+            $vis fn into_raw_opt(this: Option<Self>) -> *const T
+            where
+                T: Sized,
+            {
+                this.map_or(ptr::null(), Self::into_raw)
+            }
+
+            doc_comment! {
+                concat!("\
+Construct a new `Option<", stringify!($RcBorrow), ">` from a nullable raw pointer.
+
+# Safety
+
+The raw pointer must be null, or satisfy the requirements of
+[`", stringify!($RcBorrow), "::from_raw`]."),
+                $vis unsafe fn from_raw_opt(ptr: *const T) -> Option<Self> {
+                    ptr::NonNull::new(ptr as *mut T).map(|raw| $RcBorrow {
+                        raw,
+                        marker: PhantomData,
+                    })
+                }
+            }
+        }
+
+        impl<T: ?Sized + 'static> $RcBorrow<'static, T> {
+            doc_comment! {
+                concat!("\
+Leak a strong reference, returning a borrowed pointer which is valid forever.
+
+The allocation will never be freed, as the leaked strong reference is never released.
+
+# Examples
+
+```rust
+# use {rc_borrow::*, std::{rc::Rc, sync::Arc}};
+let leaked: ", stringify!($RcBorrow), "<'static, u32> = ", stringify!($RcBorrow), "::leak(", stringify!($Rc), "::new(0));
+let owned: ", stringify!($Rc), "<u32> = ", stringify!($RcBorrow), "::upgrade(leaked);
+assert_eq!(", stringify!($Rc), "::strong_count(&owned), 2);
+# // release the leaked reference, so the example doesn't leak under miri
+# unsafe { ", stringify!($Rc), "::decrement_strong_count(", stringify!($RcBorrow), "::into_raw(leaked)) };
+# assert_eq!(", stringify!($Rc), "::strong_count(&owned), 1);
+```"),
+                $vis fn leak(this: $Rc<T>) -> Self {
+                    unsafe { Self::from_raw($Rc::into_raw(this)) }
+                }
+            }
         }
 
         // ~~~ &T like impls ~~~ //
@@ -248,6 +377,38 @@ between the two types, and the types must be transmute-compatible."),
             fn clone(&self) -> Self { *self }
         }
 
+        #[cfg(feature = "unsize")]
+        doc_comment! {
+            concat!("\
+Unsizes a borrowed pointer using the `unsize` crate.
+
+# Usage
+
+```
+# use {rc_borrow::*, std::{fmt::Debug, rc::Rc, sync::Arc}};
+use unsize::{Coercion, CoerceUnsize};
+
+let owned = ", stringify!($Rc), "::new(42u32);
+let borrowed: ", stringify!($RcBorrow), "<'_, u32> = (&owned).into();
+let borrowed: ", stringify!($RcBorrow), "<'_, dyn Debug> =
+    borrowed.unsize(Coercion::<_, dyn Debug>::to_debug());
+assert_eq!(format!(\"{:?}\", borrowed), \"42\");
+```"),
+            unsafe impl<'a, T, U: ?Sized + 'a> unsize::CoerciblePtr<U> for $RcBorrow<'a, T> {
+                type Pointee = T;
+                type Output = $RcBorrow<'a, U>;
+                fn as_sized_ptr(&mut self) -> *mut T {
+                    self.raw.as_ptr()
+                }
+                unsafe fn replace_ptr(self, new: *mut U) -> $RcBorrow<'a, U> {
+                    $RcBorrow {
+                        raw: self.raw.replace_ptr(new),
+                        marker: PhantomData,
+                    }
+                }
+            }
+        }
+
         // CoerceUnsized is unstable
 
         impl<T: ?Sized> Copy for $RcBorrow<'_, T> {}
@@ -279,6 +440,26 @@ between the two types, and the types must be transmute-compatible."),
             }
         }
 
+        #[cfg(any(has_core_error, feature = "std"))]
+        impl<T: ?Sized> Error for $RcBorrow<'_, T>
+        where
+            T: Error,
+        {
+            #[allow(deprecated)]
+            fn description(&self) -> &str {
+                (**self).description()
+            }
+
+            #[allow(deprecated)]
+            fn cause(&self) -> Option<&dyn Error> {
+                (**self).cause()
+            }
+
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                (**self).source()
+            }
+        }
+
         impl<T: ?Sized> Eq for $RcBorrow<'_, T> where T: Eq {}
 
         // Fn, FnMut, FnOnce are unstable to implement
@@ -348,12 +529,10 @@ between the two types, and the types must be transmute-compatible."),
             }
         }
 
-        impl<T: ?Sized> Pointer for $RcBorrow<'_, T>
-        where
-            T: Pointer,
-        {
+        // NB: like &T and $Rc<T>, this formats the address, not the pointee.
+        impl<T: ?Sized> Pointer for $RcBorrow<'_, T> {
             fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                (**self).fmt(f)
+                Pointer::fmt(&self.raw, f)
             }
         }
 
@@ -393,15 +572,414 @@ between the two types, and the types must be transmute-compatible."),
     )*}
 }
 
+macro_rules! projected_rc_borrow {
+    ($($(#[$m:meta])* $vis:vis struct $Projected:ident = $RcBorrow:ident<&$Rc:ident>;)*) => {$(
+        $(#[$m])*
+        $vis struct $Projected<'a, T: ?Sized, U: ?Sized> {
+            owner: $RcBorrow<'a, T>,
+            field: &'a U,
+        }
+
+        impl<'a, T: ?Sized> $RcBorrow<'a, T> {
+            doc_comment! {
+                concat!("\
+Project this borrowed pointer to a part of its contents.
+
+The resulting `", stringify!($Projected), "` dereferences to the projected part,
+but still remembers the whole allocation, so it can be upgraded to a `", stringify!($Rc), "`.
+
+# Examples
+
+```rust
+# use {rc_borrow::*, std::{rc::Rc, sync::Arc}};
+let pair = ", stringify!($Rc), "::new((1, String::from(\"two\")));
+let borrowed: ", stringify!($RcBorrow), "<'_, (i32, String)> = (&pair).into();
+let projected = ", stringify!($RcBorrow), "::map(borrowed, |pair| &*pair.1);
+assert_eq!(&*projected, \"two\");
+let upgraded: ", stringify!($Rc), "<(i32, String)> = ", stringify!($Projected), "::upgrade(projected);
+assert!(", stringify!($Rc), "::ptr_eq(&pair, &upgraded));
+```"),
+                $vis fn map<U: ?Sized, F>(this: Self, f: F) -> $Projected<'a, T, U>
+                where
+                    F: FnOnce(&'a T) -> &'a U,
+                {
+                    $Projected {
+                        owner: this,
+                        field: f(Self::downgrade(this)),
+                    }
+                }
+            }
+        }
+
+        impl<'a, T: ?Sized, U: ?Sized> $Projected<'a, T, U> {
+            /// Project this borrowed pointer further.
+            $vis fn map<V: ?Sized, F>(this: Self, f: F) -> $Projected<'a, T, V>
+            where
+                F: FnOnce(&'a U) -> &'a V,
+            {
+                $Projected {
+                    owner: this.owner,
+                    field: f(this.field),
+                }
+            }
+
+            /// Get the borrowed pointer to the whole allocation.
+            $vis fn owner(this: Self) -> $RcBorrow<'a, T> {
+                this.owner
+            }
+
+            /// Convert this borrowed pointer into an owned pointer to the whole allocation.
+            $vis fn upgrade(this: Self) -> $Rc<T> {
+                $RcBorrow::upgrade(this.owner)
+            }
+
+            /// Convert this borrowed pointer into a standard reference to the projected part.
+            ///
+            /// This gives you a long-lived reference,
+            /// whereas dereferencing gives a temporary borrow.
+            $vis fn downgrade(this: Self) -> &'a U {
+                this.field
+            }
+        }
+
+        impl<T: ?Sized, U: ?Sized> Clone for $Projected<'_, T, U> {
+            fn clone(&self) -> Self { *self }
+        }
+
+        impl<T: ?Sized, U: ?Sized> Copy for $Projected<'_, T, U> {}
+
+        impl<T: ?Sized, U: ?Sized> Debug for $Projected<'_, T, U>
+        where
+            U: Debug
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<T: ?Sized, U: ?Sized> Deref for $Projected<'_, T, U> {
+            type Target = U;
+            fn deref(&self) -> &U {
+                self.field
+            }
+        }
+
+        impl<T: ?Sized, U: ?Sized> Display for $Projected<'_, T, U>
+        where
+            U: Display,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+    )*}
+}
+
+macro_rules! rc_or_borrow {
+    ($($(#[$m:meta])* $vis:vis enum $RcOrBorrow:ident = $RcBorrow:ident | $Rc:ident;)*) => {$(
+        $(#[$m])*
+        $vis enum $RcOrBorrow<'a, T: ?Sized> {
+            /// Borrowed pointer.
+            Borrowed($RcBorrow<'a, T>),
+            /// Owned pointer.
+            Owned($Rc<T>),
+        }
+
+        impl<'a, T: ?Sized> $RcOrBorrow<'a, T> {
+            /// Convert this pointer into an owned pointer.
+            ///
+            /// This only touches the reference count if the pointer is borrowed.
+            $vis fn into_owned(this: Self) -> $Rc<T> {
+                match this {
+                    $RcOrBorrow::Borrowed(borrowed) => $RcBorrow::upgrade(borrowed),
+                    $RcOrBorrow::Owned(owned) => owned,
+                }
+            }
+
+            /// Borrow this pointer.
+            $vis fn borrow(this: &Self) -> $RcBorrow<'_, T> {
+                match this {
+                    $RcOrBorrow::Borrowed(borrowed) => *borrowed,
+                    $RcOrBorrow::Owned(owned) => owned.into(),
+                }
+            }
+
+            /// Is this pointer owned?
+            $vis fn is_owned(this: &Self) -> bool {
+                matches!(this, $RcOrBorrow::Owned(_))
+            }
+        }
+
+        impl<T: ?Sized, U: ?Sized> AsRef<U> for $RcOrBorrow<'_, T>
+        where
+            T: AsRef<U>,
+        {
+            fn as_ref(&self) -> &U {
+                (**self).as_ref()
+            }
+        }
+
+        impl<T: ?Sized> Borrow<T> for $RcOrBorrow<'_, T> {
+            fn borrow(&self) -> &T {
+                &**self
+            }
+        }
+
+        impl<T: ?Sized> Clone for $RcOrBorrow<'_, T> {
+            fn clone(&self) -> Self {
+                match self {
+                    $RcOrBorrow::Borrowed(borrowed) => $RcOrBorrow::Borrowed(*borrowed),
+                    $RcOrBorrow::Owned(owned) => $RcOrBorrow::Owned(owned.clone()),
+                }
+            }
+        }
+
+        impl<T: ?Sized> Debug for $RcOrBorrow<'_, T>
+        where
+            T: Debug
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<T: ?Sized> Deref for $RcOrBorrow<'_, T> {
+            type Target = T;
+            fn deref(&self) -> &T {
+                match self {
+                    $RcOrBorrow::Borrowed(borrowed) => borrowed,
+                    $RcOrBorrow::Owned(owned) => owned,
+                }
+            }
+        }
+
+        impl<T: ?Sized> Display for $RcOrBorrow<'_, T>
+        where
+            T: Display,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<T: ?Sized> Eq for $RcOrBorrow<'_, T> where T: Eq {}
+
+        impl<'a, T: ?Sized> From<&'a $Rc<T>> for $RcOrBorrow<'a, T> {
+            fn from(v: &'a $Rc<T>) -> Self {
+                $RcOrBorrow::Borrowed(v.into())
+            }
+        }
+
+        impl<'a, T: ?Sized> From<$RcBorrow<'a, T>> for $RcOrBorrow<'a, T> {
+            fn from(v: $RcBorrow<'a, T>) -> Self {
+                $RcOrBorrow::Borrowed(v)
+            }
+        }
+
+        impl<T: ?Sized> From<$Rc<T>> for $RcOrBorrow<'_, T> {
+            fn from(v: $Rc<T>) -> Self {
+                $RcOrBorrow::Owned(v)
+            }
+        }
+
+        impl<T: ?Sized> Hash for $RcOrBorrow<'_, T>
+        where
+            T: Hash,
+        {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                (**self).hash(state)
+            }
+        }
+
+        impl<T: ?Sized> Ord for $RcOrBorrow<'_, T>
+        where
+            T: Ord,
+        {
+            fn cmp(&self, other: &Self) -> Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl<T: ?Sized, O> PartialEq<O> for $RcOrBorrow<'_, T>
+        where
+            O: Deref,
+            T: PartialEq<O::Target>,
+        {
+            fn eq(&self, other: &O) -> bool {
+                (**self).eq(&**other)
+            }
+        }
+
+        impl<T: ?Sized, O> PartialOrd<O> for $RcOrBorrow<'_, T>
+        where
+            O: Deref,
+            T: PartialOrd<O::Target>,
+        {
+            fn partial_cmp(&self, other: &O) -> Option<Ordering> {
+                (**self).partial_cmp(&**other)
+            }
+        }
+    )*}
+}
+
+#[cfg(feature = "erasable")]
+macro_rules! thin_rc_borrow {
+    ($($(#[$m:meta])* $vis:vis struct $ThinRcBorrow:ident = $RcBorrow:ident<&$Rc:ident>;)*) => {$(
+        $(#[$m])*
+        $vis struct $ThinRcBorrow<'a, T: ?Sized + Erasable> {
+            raw: ErasedPtr,
+            marker: PhantomData<&'a $Rc<T>>,
+        }
+
+        unsafe impl<'a, T: ?Sized + Erasable> Send for $ThinRcBorrow<'a, T> where &'a $Rc<T>: Send {}
+        unsafe impl<'a, T: ?Sized + Erasable> Sync for $ThinRcBorrow<'a, T> where &'a $Rc<T>: Sync {}
+
+        impl<'a, T: ?Sized + Erasable> From<&'a $Rc<T>> for $ThinRcBorrow<'a, T> {
+            fn from(v: &'a $Rc<T>) -> Self {
+                $RcBorrow::from(v).into()
+            }
+        }
+
+        impl<'a, T: ?Sized + Erasable> From<$RcBorrow<'a, T>> for $ThinRcBorrow<'a, T> {
+            fn from(v: $RcBorrow<'a, T>) -> Self {
+                $ThinRcBorrow {
+                    raw: ErasablePtr::erase(v),
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, T: ?Sized + Erasable> From<$ThinRcBorrow<'a, T>> for $RcBorrow<'a, T> {
+            fn from(v: $ThinRcBorrow<'a, T>) -> Self {
+                unsafe { ErasablePtr::unerase(v.raw) }
+            }
+        }
+
+        impl<'a, T: ?Sized + Erasable> $ThinRcBorrow<'a, T> {
+            /// Convert this borrowed pointer into an owned pointer.
+            $vis fn upgrade(this: Self) -> $Rc<T> {
+                $RcBorrow::upgrade(this.into())
+            }
+
+            /// Convert this borrowed pointer into an owned thin pointer.
+            $vis fn upgrade_thin(this: Self) -> Thin<$Rc<T>> {
+                Self::upgrade(this).into()
+            }
+
+            /// Convert this borrowed pointer into a standard reference.
+            ///
+            /// This gives you a long-lived reference,
+            /// whereas dereferencing gives a temporary borrow.
+            $vis fn downgrade(this: Self) -> &'a T {
+                $RcBorrow::downgrade(this.into())
+            }
+        }
+
+        unsafe impl<T: ?Sized + Erasable> ErasablePtr for $ThinRcBorrow<'_, T> {
+            #[inline(always)]
+            fn erase(this: Self) -> ErasedPtr {
+                this.raw
+            }
+
+            #[inline(always)]
+            unsafe fn unerase(this: ErasedPtr) -> Self {
+                $ThinRcBorrow {
+                    raw: this,
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<T: ?Sized + Erasable> Clone for $ThinRcBorrow<'_, T> {
+            fn clone(&self) -> Self { *self }
+        }
+
+        impl<T: ?Sized + Erasable> Copy for $ThinRcBorrow<'_, T> {}
+
+        impl<T: ?Sized + Erasable> Debug for $ThinRcBorrow<'_, T>
+        where
+            T: Debug
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<T: ?Sized + Erasable> Deref for $ThinRcBorrow<'_, T> {
+            type Target = T;
+            fn deref(&self) -> &T {
+                Self::downgrade(*self)
+            }
+        }
+
+        impl<T: ?Sized + Erasable> Display for $ThinRcBorrow<'_, T>
+        where
+            T: Display,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+    )*}
+}
+
 rc_borrow! {
     /// Borrowed version of [`Arc`].
     ///
     /// This type is guaranteed to have the same repr as `&T`.
+    /// Likewise, `Option<ArcBorrow<'_, T>>` has the same repr as `Option<&T>`,
+    /// so it is pointer-sized (for sized `T`) and `None` is represented by null.
     #[repr(transparent)]
-    pub struct ArcBorrow = &sync::Arc;
+    pub struct ArcBorrow = &sync::Arc { ptr_eq_arc };
     /// Borrowed version of [`Rc`].
     ///
     /// This type is guaranteed to have the same repr as `&T`.
+    /// Likewise, `Option<RcBorrow<'_, T>>` has the same repr as `Option<&T>`,
+    /// so it is pointer-sized (for sized `T`) and `None` is represented by null.
+    #[repr(transparent)]
+    pub struct RcBorrow = &rc::Rc { ptr_eq_rc };
+}
+
+projected_rc_borrow! {
+    /// Borrowed version of [`Arc`] projected to a part of its contents.
+    ///
+    /// This can still be upgraded to the `Arc` of the whole allocation.
+    pub struct ProjectedArcBorrow = ArcBorrow<&Arc>;
+    /// Borrowed version of [`Rc`] projected to a part of its contents.
+    ///
+    /// This can still be upgraded to the `Rc` of the whole allocation.
+    pub struct ProjectedRcBorrow = RcBorrow<&Rc>;
+}
+
+rc_or_borrow! {
+    /// Either an owned [`Arc`] or an [`ArcBorrow`].
+    ///
+    /// This is to `Arc` what `Cow` is to `Box`: APIs which only sometimes
+    /// need to retain the pointer can avoid touching the reference count
+    /// when they don't.
+    pub enum ArcOrBorrow = ArcBorrow | Arc;
+    /// Either an owned [`Rc`] or an [`RcBorrow`].
+    ///
+    /// This is to `Rc` what `Cow` is to `Box`: APIs which only sometimes
+    /// need to retain the pointer can avoid touching the reference count
+    /// when they don't.
+    pub enum RcOrBorrow = RcBorrow | Rc;
+}
+
+#[cfg(feature = "erasable")]
+thin_rc_borrow! {
+    /// Thin borrowed version of [`Arc`].
+    ///
+    /// This is like [`ArcBorrow`], but always the size of a single pointer,
+    /// as the pointee stores its own metadata. Unlike `Thin<ArcBorrow<_>>`,
+    /// it is `Copy`, and upgrades to a `Thin<Arc<_>>`.
+    #[repr(transparent)]
+    pub struct ThinArcBorrow = ArcBorrow<&Arc>;
+    /// Thin borrowed version of [`Rc`].
+    ///
+    /// This is like [`RcBorrow`], but always the size of a single pointer,
+    /// as the pointee stores its own metadata. Unlike `Thin<RcBorrow<_>>`,
+    /// it is `Copy`, and upgrades to a `Thin<Rc<_>>`.
     #[repr(transparent)]
-    pub struct RcBorrow = &rc::Rc;
+    pub struct ThinRcBorrow = RcBorrow<&Rc>;
 }
@@ -0,0 +1,24 @@
+use {
+    arc_swap::ArcSwap,
+    rc_borrow::{ArcBorrow, ArcSwapExt},
+    std::sync::Arc,
+};
+
+#[test]
+fn guard_borrow() {
+    let swap = ArcSwap::from_pointee(0);
+    let guard = swap.load();
+    let borrowed: ArcBorrow<'_, i32> = (&guard).into();
+    swap.store(Arc::new(1));
+    assert_eq!(*borrowed, 0);
+    assert_eq!(*ArcBorrow::upgrade(borrowed), 0);
+}
+
+#[test]
+fn with_borrow() {
+    let swap = ArcSwap::from_pointee(0);
+    let (value, upgraded) = swap.with_borrow(|borrowed| (*borrowed, ArcBorrow::upgrade(borrowed)));
+    swap.store(Arc::new(1));
+    assert_eq!((value, *upgraded), (0, 0));
+    assert_eq!(swap.with_borrow(|borrowed| *borrowed), 1);
+}
@@ -3,7 +3,10 @@
 
 #![allow(unused)]
 
-use {rc_borrow::ArcBorrow, std::sync::Arc};
+use {
+    rc_borrow::{ArcBorrow, ArcOrBorrow, ProjectedArcBorrow},
+    std::{error::Error, fmt, pin::Pin, sync::Arc},
+};
 
 #[test]
 fn doc_example() {
@@ -23,3 +26,164 @@ fn doc_example() {
     let cloned: Arc<Resource> = ArcBorrow::upgrade(borrowed);
     use_resource(&borrowed);
 }
+
+#[test]
+fn counts() {
+    let resource = Arc::new(0);
+    let weak = Arc::downgrade(&resource);
+    let borrowed: ArcBorrow<'_, i32> = (&resource).into();
+    assert_eq!(ArcBorrow::strong_count(borrowed), 1);
+    assert_eq!(ArcBorrow::weak_count(borrowed), 1);
+    let cloned = ArcBorrow::upgrade(borrowed);
+    assert_eq!(ArcBorrow::strong_count(borrowed), 2);
+    drop((cloned, weak));
+    assert_eq!(ArcBorrow::strong_count(borrowed), 1);
+    assert_eq!(ArcBorrow::weak_count(borrowed), 0);
+}
+
+#[test]
+fn projection() {
+    let resource = Arc::new((0u8, [1u16, 2, 3]));
+    let borrowed: ArcBorrow<'_, (u8, [u16; 3])> = (&resource).into();
+    let projected = ArcBorrow::map(borrowed, |it| &it.1);
+    let projected = ProjectedArcBorrow::map(projected, |it| &it[1]);
+    assert_eq!(*projected, 2);
+    let cloned = ProjectedArcBorrow::upgrade(projected);
+    assert!(Arc::ptr_eq(&resource, &cloned));
+}
+
+#[test]
+fn or_borrow() {
+    let resource = Arc::new(0);
+    let borrowed = ArcOrBorrow::from(&resource);
+    assert!(!ArcOrBorrow::is_owned(&borrowed));
+    assert_eq!(ArcBorrow::strong_count(ArcOrBorrow::borrow(&borrowed)), 1);
+    let owned = ArcOrBorrow::from(ArcOrBorrow::into_owned(borrowed.clone()));
+    assert!(ArcOrBorrow::is_owned(&owned));
+    assert_eq!(borrowed, owned);
+    assert_eq!(Arc::strong_count(&resource), 2);
+}
+
+#[test]
+fn raw_opt() {
+    let resource = Arc::new(0);
+    let borrowed: ArcBorrow<'_, i32> = (&resource).into();
+    let raw = ArcBorrow::into_raw_opt(Some(borrowed));
+    assert_eq!(raw, Arc::as_ptr(&resource));
+    assert!(unsafe { ArcBorrow::from_raw_opt(raw) }.is_some());
+    let null = ArcBorrow::<i32>::into_raw_opt(None);
+    assert!(null.is_null());
+    assert!(unsafe { ArcBorrow::<i32>::from_raw_opt(null) }.is_none());
+    assert_eq!(
+        std::mem::size_of::<Option<ArcBorrow<'_, i32>>>(),
+        std::mem::size_of::<*const i32>(),
+    );
+}
+
+#[test]
+fn pinned() {
+    let resource = Arc::pin(0);
+    let borrowed: Pin<ArcBorrow<'_, i32>> = (&resource).into();
+    assert_eq!(*borrowed, 0);
+    let cloned = ArcBorrow::upgrade_pin(borrowed);
+    assert_eq!(*cloned, 0);
+}
+
+#[test]
+fn error() {
+    #[derive(Debug)]
+    struct Inner;
+    impl fmt::Display for Inner {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("inner")
+        }
+    }
+    impl Error for Inner {}
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("outer")
+        }
+    }
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let resource = Arc::new(Outer(Inner));
+    let borrowed: ArcBorrow<'_, Outer> = (&resource).into();
+    let error: &dyn Error = &borrowed;
+    assert_eq!(error.source().unwrap().to_string(), "inner");
+}
+
+#[test]
+fn pinned_ref() {
+    let resource = Arc::new(0);
+    let pinned: Pin<&Arc<i32>> = Pin::new(&resource);
+    let borrowed: ArcBorrow<'_, i32> = pinned.into();
+    assert_eq!(*borrowed, 0);
+}
+
+#[test]
+fn thin() {
+    use {erasable::Thin, rc_borrow::ThinArcBorrow};
+
+    let resource = Arc::new(0);
+    let borrowed: ThinArcBorrow<'_, i32> = (&resource).into();
+    assert_eq!(*borrowed, 0);
+    let cloned: Thin<Arc<i32>> = ThinArcBorrow::upgrade_thin(borrowed);
+    assert_eq!(*cloned, 0);
+    assert_eq!(ArcBorrow::strong_count(borrowed.into()), 2);
+}
+
+#[test]
+fn to_weak() {
+    let resource = Arc::new(0);
+    let borrowed: ArcBorrow<'_, i32> = (&resource).into();
+    let weak = ArcBorrow::to_weak(borrowed);
+    assert_eq!(ArcBorrow::strong_count(borrowed), 1);
+    assert_eq!(ArcBorrow::weak_count(borrowed), 1);
+    assert!(weak.upgrade().is_some());
+}
+
+#[test]
+fn pointer_fmt() {
+    let resource = Arc::new(0);
+    let borrowed: ArcBorrow<'_, i32> = (&resource).into();
+    assert_eq!(format!("{:p}", borrowed), format!("{:p}", resource));
+}
+
+#[test]
+fn ptr_eq() {
+    let a = Arc::new(0);
+    let b = Arc::new(0);
+    let borrowed_a: ArcBorrow<'_, i32> = (&a).into();
+    let borrowed_b: ArcBorrow<'_, i32> = (&b).into();
+    assert!(ArcBorrow::ptr_eq(borrowed_a, borrowed_a));
+    assert!(!ArcBorrow::ptr_eq(borrowed_a, borrowed_b));
+    assert!(ArcBorrow::ptr_eq_arc(borrowed_a, &a));
+    assert!(!ArcBorrow::ptr_eq_arc(borrowed_a, &b));
+}
+
+#[test]
+fn from_ref() {
+    let resource = Arc::new(0);
+    let reference: &i32 = &resource;
+    let borrowed = unsafe { ArcBorrow::from_ref(reference) };
+    assert!(ArcBorrow::ptr_eq_arc(borrowed, &resource));
+    assert_eq!(ArcBorrow::strong_count(borrowed), 1);
+}
+
+#[test]
+fn addr() {
+    let resource = Arc::new(0);
+    let borrowed: ArcBorrow<'_, i32> = (&resource).into();
+    assert_eq!(ArcBorrow::addr(borrowed), Arc::as_ptr(&resource) as usize);
+    assert_eq!(
+        ArcBorrow::as_non_null(borrowed).as_ptr() as *const i32,
+        Arc::as_ptr(&resource),
+    );
+}
@@ -0,0 +1,14 @@
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(has_core_error, has_new_uninit)");
+
+    let cfg = autocfg::new();
+
+    if cfg.probe_rustc_version(1, 81) {
+        autocfg::emit("has_core_error");
+    }
+    if cfg.probe_rustc_version(1, 82) {
+        autocfg::emit("has_new_uninit");
+    }
+
+    autocfg::rerun_path("build.rs");
+}
@@ -11,33 +11,78 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 #![no_std]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(
+    feature = "coerce_unsized",
+    feature(coerce_unsized, dispatch_from_dyn, unsize)
+)]
+#![cfg_attr(feature = "dropck_eyepatch", feature(dropck_eyepatch))]
+#![cfg_attr(
+    feature = "fn_traits",
+    feature(fn_traits, tuple_trait, unboxed_closures)
+)]
 
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(has_core_error)]
+use core::error::Error;
+#[cfg(feature = "fn_traits")]
+use core::marker::Tuple;
+#[cfg(feature = "coerce_unsized")]
+use core::{
+    marker::Unsize,
+    ops::{CoerceUnsized, DispatchFromDyn},
+};
 #[cfg(feature = "erasable")]
 use erasable::{Erasable, ErasablePtr, ErasedPtr};
 #[cfg(feature = "slice-dst")]
 use slice_dst::{AllocSliceDst, SliceDst, TryAllocSliceDst};
+#[cfg(all(not(has_core_error), feature = "std"))]
+use std::error::Error;
 #[cfg(feature = "std")]
-use std::panic::UnwindSafe;
+use std::{
+    ffi::{OsStr, OsString},
+    io::{self, BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write},
+    panic::UnwindSafe,
+    path::{Path, PathBuf},
+};
+#[cfg(feature = "allocator_api")]
+use {
+    alloc::alloc::Global,
+    core::alloc::{AllocError, Allocator},
+};
 use {
-    alloc::{boxed::Box, rc::Rc, string::String, sync::Arc, vec::Vec},
+    alloc::{
+        alloc::{alloc, handle_alloc_error},
+        borrow::{Cow, ToOwned},
+        boxed::Box,
+        ffi::CString,
+        rc::{self, Rc},
+        string::String,
+        sync::{self, Arc},
+        vec::Vec,
+    },
     core::{
+        alloc::Layout,
         any::Any,
         borrow::{Borrow, BorrowMut},
         cmp::Ordering,
-        convert::{TryFrom, TryInto},
+        convert::{Infallible, TryFrom, TryInto},
+        ffi::CStr,
         fmt::{self, Debug, Display, Formatter, Pointer},
+        future::Future,
         hash::{Hash, Hasher},
         hint::unreachable_unchecked,
         iter::{FromIterator, FusedIterator},
         marker::PhantomData,
-        mem::ManuallyDrop,
+        mem::{self, ManuallyDrop, MaybeUninit},
         ops::{Deref, DerefMut},
         pin::Pin,
         ptr,
+        str::{self, FromStr, Utf8Error},
+        task::{Context, Poll},
     },
 };
 
@@ -48,8 +93,56 @@ macro_rules! doc_comment {
     };
 }
 
+/// Drops the initialized prefix of a partially initialized slice on unwind.
+struct DropInitialized<T> {
+    ptr: *mut T,
+    len: usize,
+}
+
+impl<T> Drop for DropInitialized<T> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, self.len)) }
+    }
+}
+
+/// Counts the length of formatted output.
+struct CountWriter(usize);
+
+impl fmt::Write for CountWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.checked_add(s.len()).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// Writes formatted output into a fixed size buffer.
+struct SliceWriter<'a> {
+    buf: &'a mut [MaybeUninit<u8>],
+    len: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let dst = self
+            .buf
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?;
+        unsafe { ptr::copy_nonoverlapping(s.as_ptr(), dst.as_mut_ptr().cast::<u8>(), s.len()) };
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+/// Replace the data pointer of a potentially fat pointer, keeping the metadata.
+unsafe fn set_ptr_value<T: ?Sized>(mut this: *mut T, val: *mut u8) -> *mut T {
+    // NB: this is how std's (unstable) `set_ptr_value` is implemented;
+    // the data pointer is always the first field of a (potentially fat) pointer.
+    ptr::write(&mut this as *mut *mut T as *mut *mut u8, val);
+    this
+}
+
 macro_rules! rc_box {
-    ($($(#[$m:meta])* $RcBox:ident = $Rc:ident)*) => {$(
+    ($($(#[$m:meta])* $RcBox:ident, $RcBoxGuard:ident = $Rc:ident in $rc:ident { $from_rc_unchecked:ident, $into_pin_rc:ident, $into_rc_with_weak:ident })*) => {$(
         $(#[$m])*
         pub struct $RcBox<T: ?Sized> {
             raw: ptr::NonNull<T>,
@@ -59,12 +152,21 @@ macro_rules! rc_box {
         unsafe impl<T: ?Sized> Send for $RcBox<T> where Box<T>: Send {}
         unsafe impl<T: ?Sized> Sync for $RcBox<T> where Box<T>: Sync {}
 
+        #[cfg(not(feature = "dropck_eyepatch"))]
         impl<T: ?Sized> Drop for $RcBox<T> {
             fn drop(&mut self) {
                 unsafe { drop($Rc::<T>::from(ptr::read(self))) }
             }
         }
 
+        // NB: the `PhantomData<$Rc<T>>` marker tells dropck that we drop a `T`.
+        #[cfg(feature = "dropck_eyepatch")]
+        unsafe impl<#[may_dangle] T: ?Sized> Drop for $RcBox<T> {
+            fn drop(&mut self) {
+                unsafe { drop($Rc::<T>::from(ptr::read(self))) }
+            }
+        }
+
         impl<T: ?Sized> From<$RcBox<T>> for $Rc<T> {
             fn from(v: $RcBox<T>) -> $Rc<T> {
                 unsafe { $Rc::from_raw($RcBox::into_raw(v).as_ptr()) }
@@ -76,6 +178,8 @@ macro_rules! rc_box {
             fn try_from(mut v: $Rc<T>) -> Result<$RcBox<T>, $Rc<T>> {
                 // Could this just be `$Rc::strong_count == 1 && $Rc::weak_count == 0`?
                 // I _think_ `get_mut` has the weaker synchronization requirements?
+                // NB: outstanding `Weak`s must be refused, as they could be upgraded while
+                // we hand out `&mut T`, and the public API gives us no way to block that.
                 if $Rc::get_mut(&mut v).is_some() {
                     unsafe { Ok($RcBox::from_raw($Rc::into_raw(v))) }
                 } else {
@@ -84,6 +188,12 @@ macro_rules! rc_box {
             }
         }
 
+        impl<T: ?Sized> From<Pin<$RcBox<T>>> for Pin<$Rc<T>> {
+            fn from(v: Pin<$RcBox<T>>) -> Pin<$Rc<T>> {
+                unsafe { Pin::new_unchecked(Pin::into_inner_unchecked(v).into()) }
+            }
+        }
+
         impl<T: ?Sized> TryFrom<Pin<$Rc<T>>> for Pin<$RcBox<T>> {
             type Error = Pin<$Rc<T>>;
             fn try_from(v: Pin<$Rc<T>>) -> Result<Pin<$RcBox<T>>, Pin<$Rc<T>>> {
@@ -106,6 +216,41 @@ macro_rules! rc_box {
             }
         }
 
+        doc_comment! {
+            concat!("\
+Scoped unique access to a `", stringify!($Rc), "`, created by [`", stringify!($RcBox), "::make_unique`]
+or [`", stringify!($RcBox), "::make_mut`].
+
+Like `", stringify!($RcBox), "`, this derefs mutably, but it borrows the `", stringify!($Rc), "` in place
+rather than taking ownership of it."),
+            pub struct $RcBoxGuard<'a, T: ?Sized> {
+                rc: &'a mut $Rc<T>,
+            }
+        }
+
+        impl<T: ?Sized> Debug for $RcBoxGuard<'_, T>
+        where
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<T: ?Sized> Deref for $RcBoxGuard<'_, T> {
+            type Target = T;
+            fn deref(&self) -> &T {
+                &**self.rc
+            }
+        }
+
+        impl<T: ?Sized> DerefMut for $RcBoxGuard<'_, T> {
+            fn deref_mut(&mut self) -> &mut T {
+                // SAFETY: uniqueness was checked on creation, and we hold the only `$Rc` mutably
+                unsafe { &mut *($Rc::as_ptr(self.rc) as *mut T) }
+            }
+        }
+
         // ~~~ $Rc<T> and Box<T> like inherent impls ~~~ //
 
         impl $RcBox<dyn Any + 'static> {
@@ -149,6 +294,42 @@ activate the `unsize` feature to convert the pointer via an explicit method call
                     }
                 }
             }
+
+            doc_comment! {
+                concat!("\
+Downcast the box to a concrete type, without checking the type.
+
+For a safe alternative see [`", stringify!($RcBox), "::downcast`].
+
+# Examples
+
+```rust
+# use rc_box::*; use std::convert::TryInto;
+# use std::rc::Rc; use std::sync::Arc;
+use std::any::Any;
+
+let x: ", stringify!($Rc), "<dyn Any> = ", stringify!($Rc), "::new(1_usize);
+let x: ", stringify!($RcBox), "<dyn Any> = x.try_into().unwrap();
+let ptr = ", stringify!($RcBox), "::as_raw(&x).cast::<usize>();
+
+let x = unsafe { x.downcast_unchecked::<usize>() };
+assert_eq!(*x, 1);
+assert_eq!(", stringify!($RcBox), "::as_raw(&x), ptr);
+```
+
+# Safety
+
+The contained value must be of type `T`.
+Calling this method with the incorrect type is *undefined behavior*."),
+                #[inline]
+                pub unsafe fn downcast_unchecked<T>(self) -> $RcBox<T>
+                where T: Any,
+                {
+                    debug_assert!(self.is::<T>());
+                    let raw: *mut dyn Any = Self::into_raw(self).as_ptr();
+                    $RcBox::from_raw(raw as *mut T)
+                }
+            }
         }
 
         impl $RcBox<dyn Any + 'static + Send> {
@@ -192,6 +373,42 @@ activate the `unsize` feature to convert the pointer via an explicit method call
                     }
                 }
             }
+
+            doc_comment! {
+                concat!("\
+Downcast the box to a concrete type, without checking the type.
+
+For a safe alternative see [`", stringify!($RcBox), "::downcast`].
+
+# Examples
+
+```rust
+# use rc_box::*; use std::convert::TryInto;
+# use std::rc::Rc; use std::sync::Arc;
+use std::any::Any;
+
+let x: ", stringify!($Rc), "<dyn Any + Send> = ", stringify!($Rc), "::new(1_usize);
+let x: ", stringify!($RcBox), "<dyn Any + Send> = x.try_into().unwrap();
+let ptr = ", stringify!($RcBox), "::as_raw(&x).cast::<usize>();
+
+let x = unsafe { x.downcast_unchecked::<usize>() };
+assert_eq!(*x, 1);
+assert_eq!(", stringify!($RcBox), "::as_raw(&x), ptr);
+```
+
+# Safety
+
+The contained value must be of type `T`.
+Calling this method with the incorrect type is *undefined behavior*."),
+                #[inline]
+                pub unsafe fn downcast_unchecked<T>(self) -> $RcBox<T>
+                where T: Any,
+                {
+                    debug_assert!(self.is::<T>());
+                    let raw: *mut (dyn Any + Send) = Self::into_raw(self).as_ptr();
+                    $RcBox::from_raw(raw as *mut T)
+                }
+            }
         }
 
         impl $RcBox<dyn Any + 'static + Send + Sync> {
@@ -235,10 +452,178 @@ activate the `unsize` feature to convert the pointer via an explicit method call
                     }
                 }
             }
+
+            doc_comment! {
+                concat!("\
+Downcast the box to a concrete type, without checking the type.
+
+For a safe alternative see [`", stringify!($RcBox), "::downcast`].
+
+# Examples
+
+```rust
+# use rc_box::*; use std::convert::TryInto;
+# use std::rc::Rc; use std::sync::Arc;
+use std::any::Any;
+
+let x: ", stringify!($Rc), "<dyn Any + Send + Sync> = ", stringify!($Rc), "::new(1_usize);
+let x: ", stringify!($RcBox), "<dyn Any + Send + Sync> = x.try_into().unwrap();
+let ptr = ", stringify!($RcBox), "::as_raw(&x).cast::<usize>();
+
+let x = unsafe { x.downcast_unchecked::<usize>() };
+assert_eq!(*x, 1);
+assert_eq!(", stringify!($RcBox), "::as_raw(&x), ptr);
+```
+
+# Safety
+
+The contained value must be of type `T`.
+Calling this method with the incorrect type is *undefined behavior*."),
+                #[inline]
+                pub unsafe fn downcast_unchecked<T>(self) -> $RcBox<T>
+                where T: Any,
+                {
+                    debug_assert!(self.is::<T>());
+                    let raw: *mut (dyn Any + Send + Sync) = Self::into_raw(self).as_ptr();
+                    $RcBox::from_raw(raw as *mut T)
+                }
+            }
+        }
+
+        #[cfg(any(has_core_error, feature = "std"))]
+        impl $RcBox<dyn Error + 'static> {
+            doc_comment! {
+                concat!("Attempt to downcast the box to a concrete error type.
+
+# Examples
+
+```rust
+# use rc_box::*; use std::convert::TryInto;
+# use std::rc::Rc; use std::sync::Arc;
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+struct MyError;
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(\"my error\")
+    }
+}
+
+impl Error for MyError {}
+
+let error: ", stringify!($Rc), "<dyn Error> = ", stringify!($Rc), "::new(MyError);
+let error: ", stringify!($RcBox), "<dyn Error> = error.try_into().unwrap();
+assert!(error.downcast::<MyError>().is_ok());
+```"),
+                #[inline]
+                pub fn downcast<T>(self) -> Result<$RcBox<T>, Self>
+                where T: Error + 'static,
+                {
+                    if self.is::<T>() {
+                        unsafe {
+                            let raw: *mut dyn Error = Self::into_raw(self).as_ptr();
+                            Ok($RcBox::from_raw(raw as *mut T))
+                        }
+                    } else {
+                        Err(self)
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(has_core_error, feature = "std"))]
+        impl $RcBox<dyn Error + 'static + Send> {
+            doc_comment! {
+                concat!("Attempt to downcast the box to a concrete error type.
+
+# Examples
+
+```rust
+# use rc_box::*; use std::convert::TryInto;
+# use std::rc::Rc; use std::sync::Arc;
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+struct MyError;
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(\"my error\")
+    }
+}
+
+impl Error for MyError {}
+
+let error: ", stringify!($Rc), "<dyn Error + Send> = ", stringify!($Rc), "::new(MyError);
+let error: ", stringify!($RcBox), "<dyn Error + Send> = error.try_into().unwrap();
+assert!(error.downcast::<MyError>().is_ok());
+```"),
+                #[inline]
+                pub fn downcast<T>(self) -> Result<$RcBox<T>, Self>
+                where T: Error + Send + 'static,
+                {
+                    if self.is::<T>() {
+                        unsafe {
+                            let raw: *mut (dyn Error + Send) = Self::into_raw(self).as_ptr();
+                            Ok($RcBox::from_raw(raw as *mut T))
+                        }
+                    } else {
+                        Err(self)
+                    }
+                }
+            }
+        }
+
+        #[cfg(any(has_core_error, feature = "std"))]
+        impl $RcBox<dyn Error + 'static + Send + Sync> {
+            doc_comment! {
+                concat!("Attempt to downcast the box to a concrete error type.
+
+# Examples
+
+```rust
+# use rc_box::*; use std::convert::TryInto;
+# use std::rc::Rc; use std::sync::Arc;
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+struct MyError;
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(\"my error\")
+    }
+}
+
+impl Error for MyError {}
+
+let error: ", stringify!($Rc), "<dyn Error + Send + Sync> = ", stringify!($Rc), "::new(MyError);
+let error: ", stringify!($RcBox), "<dyn Error + Send + Sync> = error.try_into().unwrap();
+assert!(error.downcast::<MyError>().is_ok());
+```"),
+                #[inline]
+                pub fn downcast<T>(self) -> Result<$RcBox<T>, Self>
+                where T: Error + Send + Sync + 'static,
+                {
+                    if self.is::<T>() {
+                        unsafe {
+                            let raw: *mut (dyn Error + Send + Sync) = Self::into_raw(self).as_ptr();
+                            Ok($RcBox::from_raw(raw as *mut T))
+                        }
+                    } else {
+                        Err(self)
+                    }
+                }
+            }
         }
 
         impl<T: ?Sized> $RcBox<T> {
             // `downgrade` makes no sense as it would always immediately drop.
+            // `new_cyclic` is unsound: the weak handle could be upgraded while we hold `&mut T`.
+            // (std's unstable `UniqueRc` avoids this by keeping the strong count at zero,
+            // but we're limited to the public API, which can't represent that state.)
 
             doc_comment! {
                 concat!("Construct an ", stringify!($RcBox), " from a raw pointer.
@@ -258,56 +643,162 @@ where the `", stringify!($Rc), "` is known unique."),
             }
 
             doc_comment! {
-                concat!("Get a mutable reference into the `", stringify!($RcBox), "`.
+                concat!("\
+Borrow a `", stringify!($Rc), "` uniquely in place, if it is unique.
 
-This method exists only for API compatibility with `", stringify!($Rc), "`.
-Use `DerefMut` instead."),
-                #[deprecated(note = "Use [`DerefMut`](#impl-DerefMut) instead")]
-                pub fn get_mut(this: &mut Self) -> Option<&mut T> {
-                    Some(&mut **this)
-                }
-            }
+This returns `None` if there are other `", stringify!($Rc), "` or `Weak` pointers to the same allocation.
 
-            doc_comment! {
-                concat!("Get a mutable reference into the `", stringify!($RcBox), "`.
+# Examples
 
-This method exists only for API compatibility with `", stringify!($Rc), "`.
-Use `DerefMut` instead."),
-                #[deprecated(note = "Use [`DerefMut`](#impl-DerefMut) instead")]
-                pub fn get_mut_unchecked(this: &mut Self) -> &mut T {
-                    &mut **this
+```rust
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+let mut shared = ", stringify!($Rc), "::new(0);
+*", stringify!($RcBox), "::make_unique(&mut shared).unwrap() += 1;
+assert_eq!(*shared, 1);
+
+let other = shared.clone();
+assert!(", stringify!($RcBox), "::make_unique(&mut shared).is_none());
+```"),
+                pub fn make_unique(this: &mut $Rc<T>) -> Option<$RcBoxGuard<'_, T>> {
+                    if $Rc::get_mut(this).is_some() {
+                        Some($RcBoxGuard { rc: this })
+                    } else {
+                        None
+                    }
                 }
             }
 
             doc_comment! {
                 concat!("\
-Returns a raw pointer to the object `T` pointed to by this `", stringify!($RcBox), "`.
+Borrow a `", stringify!($Rc), "` uniquely in place, cloning the value if it is shared.
 
-Note that this returns a [`ptr::NonNull`], not a raw pointer.
-That makes this function equivalent to `as_raw_non_null`."),
-                pub fn as_raw(this: &Self) -> ptr::NonNull<T> {
-                    this.raw
+See [`", stringify!($Rc), "::make_mut`] for details.
+
+# Examples
+
+```rust
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+let mut shared = ", stringify!($Rc), "::new(0);
+let other = shared.clone();
+*", stringify!($RcBox), "::make_mut(&mut shared) += 1;
+assert_eq!((*shared, *other), (1, 0));
+```"),
+                pub fn make_mut(this: &mut $Rc<T>) -> $RcBoxGuard<'_, T>
+                where
+                    T: Clone,
+                {
+                    $Rc::make_mut(this);
+                    $RcBoxGuard { rc: this }
                 }
             }
 
             doc_comment! {
                 concat!("\
-Consume the `", stringify!($RcBox), "`, returning the wrapped pointer.
+Convert a `", stringify!($Rc), "` into a `", stringify!($RcBox), "` without checking uniqueness.
 
-To avoid a memory leak, the pointer must be converted back to a `",
-stringify!($RcBox), "`, using [`", stringify!($RcBox), "::from_raw`],
-or directly into a `", stringify!($Rc), "`, using [`", stringify!($Rc), "::from_raw`].
+# Safety
 
-Note that this returns a [`ptr::NonNull`], not a raw pointer.
-That makes this function equivalent to `into_raw_non_null`."),
-                pub fn into_raw(this: Self) -> ptr::NonNull<T> {
-                    $RcBox::as_raw(&ManuallyDrop::new(this))
+There must be no other `", stringify!($Rc), "` or `Weak` pointers to the same allocation.
+
+# Examples
+
+```rust
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+let shared = ", stringify!($Rc), "::new(0);
+let mut unique = unsafe { ", stringify!($RcBox), "::", stringify!($from_rc_unchecked), "(shared) };
+*unique += 1;
+assert_eq!(*unique, 1);
+```"),
+                pub unsafe fn $from_rc_unchecked(mut v: $Rc<T>) -> Self {
+                    debug_assert!($Rc::get_mut(&mut v).is_some());
+                    $RcBox::from_raw($Rc::into_raw(v))
                 }
             }
 
             doc_comment! {
-                concat!("Consume and leak the `", stringify!($RcBox), "`."),
-                pub fn leak<'a>(this: Self) -> &'a mut T
+                concat!("\
+Attempt to convert a `", stringify!($Rc), "` into a `", stringify!($RcBox), "`.
+
+This is the same as the `TryFrom` conversion, except that on failure, the error
+also records the strong and weak counts that were observed.
+
+# Examples
+
+```rust
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+let shared = ", stringify!($Rc), "::new(0);
+let weak = ", stringify!($Rc), "::downgrade(&shared);
+let error = ", stringify!($RcBox), "::try_from_with_counts(shared).unwrap_err();
+assert_eq!((error.strong_count(), error.weak_count()), (1, 1));
+
+drop(weak);
+let shared = error.into_inner();
+assert!(", stringify!($RcBox), "::try_from_with_counts(shared).is_ok());
+```"),
+                pub fn try_from_with_counts(v: $Rc<T>) -> Result<Self, NotUniqueError<$Rc<T>>> {
+                    $RcBox::try_from(v).map_err(|v| NotUniqueError {
+                        strong_count: $Rc::strong_count(&v),
+                        weak_count: $Rc::weak_count(&v),
+                        ptr: v,
+                    })
+                }
+            }
+
+            doc_comment! {
+                concat!("Get a mutable reference into the `", stringify!($RcBox), "`.
+
+This method exists only for API compatibility with `", stringify!($Rc), "`.
+Use `DerefMut` instead."),
+                #[deprecated(note = "Use [`DerefMut`](#impl-DerefMut) instead")]
+                pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+                    Some(&mut **this)
+                }
+            }
+
+            doc_comment! {
+                concat!("Get a mutable reference into the `", stringify!($RcBox), "`.
+
+This method exists only for API compatibility with `", stringify!($Rc), "`.
+Use `DerefMut` instead."),
+                #[deprecated(note = "Use [`DerefMut`](#impl-DerefMut) instead")]
+                pub fn get_mut_unchecked(this: &mut Self) -> &mut T {
+                    &mut **this
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Returns a raw pointer to the object `T` pointed to by this `", stringify!($RcBox), "`.
+
+Note that this returns a [`ptr::NonNull`], not a raw pointer.
+That makes this function equivalent to `as_raw_non_null`."),
+                pub fn as_raw(this: &Self) -> ptr::NonNull<T> {
+                    this.raw
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Consume the `", stringify!($RcBox), "`, returning the wrapped pointer.
+
+To avoid a memory leak, the pointer must be converted back to a `",
+stringify!($RcBox), "`, using [`", stringify!($RcBox), "::from_raw`],
+or directly into a `", stringify!($Rc), "`, using [`", stringify!($Rc), "::from_raw`].
+
+Note that this returns a [`ptr::NonNull`], not a raw pointer.
+That makes this function equivalent to `into_raw_non_null`."),
+                pub fn into_raw(this: Self) -> ptr::NonNull<T> {
+                    $RcBox::as_raw(&ManuallyDrop::new(this))
+                }
+            }
+
+            doc_comment! {
+                concat!("Consume and leak the `", stringify!($RcBox), "`."),
+                pub fn leak<'a>(this: Self) -> &'a mut T
                 where
                     T: 'a,
                 {
@@ -325,7 +816,234 @@ That makes this function equivalent to `into_raw_non_null`."),
                 }
             }
 
-            // `new_uninit`/`new_uninit_slice` are unstable but probably desirable.
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " with uninitialized contents.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let mut five = ", stringify!($RcBox), "::<u32>::new_uninit();
+five.write(5);
+let five = unsafe { five.assume_init() };
+assert_eq!(*five, 5);
+```"),
+                pub fn new_uninit() -> $RcBox<MaybeUninit<T>>
+                where
+                    T: Sized,
+                {
+                    #[cfg(has_new_uninit)]
+                    #[allow(clippy::incompatible_msrv)] // gated on has_new_uninit
+                    unsafe {
+                        $RcBox::from_unchecked($Rc::new_uninit())
+                    }
+                    #[cfg(not(has_new_uninit))]
+                    $RcBox::new(MaybeUninit::uninit())
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Create a new ", stringify!($RcBox), " from the value returned by `f`.
+
+This allocates before calling `f`, which gives the optimizer the best chance
+to construct the value directly in the allocation rather than on the stack.
+As with `", stringify!($RcBox), "::new`, this is not guaranteed;
+use [`", stringify!($RcBox), "::new_in_place`] if you need to avoid the stack.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let buffer = ", stringify!($RcBox), "::new_with(|| [0u8; 1024]);
+assert_eq!(buffer.len(), 1024);
+```"),
+                pub fn new_with<F>(f: F) -> Self
+                where
+                    T: Sized,
+                    F: FnOnce() -> T,
+                {
+                    let mut this = $RcBox::new_uninit();
+                    this.write(f());
+                    unsafe { this.assume_init() }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Create a new ", stringify!($RcBox), ", initializing the value in place with `init`.
+
+# Safety
+
+`init` must fully initialize the value it is given (or panic).
+
+# Examples
+
+```rust
+# use rc_box::*;
+let buffer = unsafe {
+    ", stringify!($RcBox), "::<[u8; 1024]>::new_in_place(|uninit| {
+        uninit.as_mut_ptr().write_bytes(1, 1);
+    })
+};
+assert!(buffer.iter().all(|&b| b == 1));
+```"),
+                pub unsafe fn new_in_place<F>(init: F) -> Self
+                where
+                    T: Sized,
+                    F: FnOnce(&mut MaybeUninit<T>),
+                {
+                    let mut this = $RcBox::new_uninit();
+                    init(&mut this);
+                    this.assume_init()
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " with uninitialized contents,
+with the memory being filled with `0` bytes.
+
+See [`MaybeUninit::zeroed`] for examples of correct and incorrect usage of this method.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let zero = ", stringify!($RcBox), "::<u32>::new_zeroed();
+let zero = unsafe { zero.assume_init() };
+assert_eq!(*zero, 0);
+```"),
+                pub fn new_zeroed() -> $RcBox<MaybeUninit<T>>
+                where
+                    T: Sized,
+                {
+                    let mut this = $RcBox::new_uninit();
+                    unsafe { ptr::write_bytes(this.as_mut_ptr(), 0, 1) };
+                    this
+                }
+            }
+
+            #[cfg(feature = "allocator_api")]
+            doc_comment! {
+                concat!("\
+Create a new ", stringify!($RcBox), ", returning an error if the allocation fails.
+
+# Examples
+
+```rust
+#![feature(allocator_api)]
+# use rc_box::*;
+let five = ", stringify!($RcBox), "::try_new(5)?;
+assert_eq!(*five, 5);
+# Ok::<(), std::alloc::AllocError>(())
+```"),
+                pub fn try_new(data: T) -> Result<Self, AllocError>
+                where
+                    T: Sized,
+                {
+                    unsafe { $Rc::try_new(data).map(|x| $RcBox::from_unchecked(x)) }
+                }
+            }
+
+            #[cfg(feature = "allocator_api")]
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " with uninitialized contents,
+returning an error if the allocation fails."),
+                pub fn try_new_uninit() -> Result<$RcBox<MaybeUninit<T>>, AllocError>
+                where
+                    T: Sized,
+                {
+                    unsafe { $Rc::try_new_uninit().map(|x| $RcBox::from_unchecked(x)) }
+                }
+            }
+
+            #[cfg(feature = "allocator_api")]
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " with uninitialized contents,
+with the memory being filled with `0` bytes,
+returning an error if the allocation fails."),
+                pub fn try_new_zeroed() -> Result<$RcBox<MaybeUninit<T>>, AllocError>
+                where
+                    T: Sized,
+                {
+                    let mut this = $RcBox::try_new_uninit()?;
+                    unsafe { ptr::write_bytes(this.as_mut_ptr(), 0, 1) };
+                    Ok(this)
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Convert a `", stringify!($RcBox), "<T>` into a `Pin<", stringify!($RcBox), "<T>>`.
+If `T` does not implement [`Unpin`], then `*this` will be pinned in memory and unable to be moved.
+
+This conversion does not allocate on the heap and happens in place.
+This is also available via [`From`].
+
+The pinned box can still be mutated through [`Pin::as_mut`],
+and frozen into a `Pin<", stringify!($Rc), "<T>>` via [`From`].
+If `T` implements `Unpin`, you can get the value back out with
+[`Pin::into_inner`] and [`", stringify!($RcBox), "::into_inner`].
+
+# Examples
+
+```rust
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+use std::{marker::PhantomPinned, pin::Pin};
+
+struct Node {
+    value: u32,
+    _pinned: PhantomPinned,
+}
+
+impl Node {
+    fn set(self: Pin<&mut Self>, value: u32) {
+        unsafe { self.get_unchecked_mut().value = value }
+    }
+}
+
+let unique = ", stringify!($RcBox), "::new(Node { value: 0, _pinned: PhantomPinned });
+let mut pinned = ", stringify!($RcBox), "::into_pin(unique);
+pinned.as_mut().set(42);
+let shared: Pin<", stringify!($Rc), "<Node>> = pinned.into();
+assert_eq!(shared.value, 42);
+```"),
+                pub fn into_pin(this: Self) -> Pin<Self> {
+                    // SAFETY: like `Box`, the contents of an `$RcBox` aren't moved
+                    // when the box is, and can't be moved out once it's pinned.
+                    unsafe { Pin::new_unchecked(this) }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Pin this `", stringify!($RcBox), "` and convert it into a shared `Pin<", stringify!($Rc), "<T>>`.
+
+If `T` does not implement [`Unpin`], then the value will be pinned in memory
+and unable to be moved from this point on.
+This is the same as [`", stringify!($RcBox), "::into_pin`] followed by [`From`].
+
+# Examples
+
+```rust
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+use std::pin::Pin;
+
+let mut unique = ", stringify!($RcBox), "::new(0);
+*unique += 1;
+let shared: Pin<", stringify!($Rc), "<i32>> = ", stringify!($RcBox), "::", stringify!($into_pin_rc), "(unique);
+assert_eq!(*shared, 1);
+```"),
+                pub fn $into_pin_rc(this: Self) -> Pin<$Rc<T>> {
+                    $RcBox::into_pin(this).into()
+                }
+            }
 
             doc_comment! {
                 concat!("\
@@ -343,15 +1061,461 @@ then the data will be pinned in memory and unable to be moved."),
                 }
             }
 
-            doc_comment! {
-                concat!("Deconstruct this `", stringify!($RcBox), "`, returning the inner value."),
-                pub fn into_inner(this: Self) -> T
-                where
-                    T: Sized,
-                {
-                    let rc: $Rc<T> = this.into();
-                    $Rc::try_unwrap(rc).unwrap_or_else(|_| unsafe { unreachable_unchecked() })
+            doc_comment! {
+                concat!("\
+Transform the value in place, reusing the allocation.
+
+# Panics
+
+Panics if `T` and `U` do not have the same size and alignment.
+If `f` panics, the allocation is still freed.
+
+# Examples
+
+```rust
+# use rc_box::*;
+struct Buffer([u8; 4096]);
+
+let raw = ", stringify!($RcBox), "::new([7u8; 4096]);
+let buffer = ", stringify!($RcBox), "::map_in_place(raw, Buffer);
+assert_eq!(buffer.0[0], 7);
+```"),
+                pub fn map_in_place<U, F>(this: Self, f: F) -> $RcBox<U>
+                where
+                    T: Sized,
+                    F: FnOnce(T) -> U,
+                {
+                    assert!(
+                        Layout::new::<T>() == Layout::new::<U>(),
+                        "map_in_place requires T and U to have the same layout",
+                    );
+                    unsafe {
+                        let this: $RcBox<MaybeUninit<T>> =
+                            $RcBox::from_raw($RcBox::into_raw(this).cast().as_ptr());
+                        let value = this.assume_init_read();
+                        // NB: cast before calling `f`, so a panic frees the allocation.
+                        let mut this: $RcBox<MaybeUninit<U>> =
+                            $RcBox::from_raw($RcBox::into_raw(this).cast().as_ptr());
+                        this.write(f(value));
+                        this.assume_init()
+                    }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Move the value into a new `Box`, freeing the reference counted allocation.
+
+This works for unsized `T` as well, by copying the value's bytes.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let unique = ", stringify!($RcBox), "::<[u32]>::from(vec![1, 2, 3]);
+let boxed: Box<[u32]> = ", stringify!($RcBox), "::into_box(unique);
+assert_eq!(*boxed, [1, 2, 3]);
+```"),
+                pub fn into_box(this: Self) -> Box<T> {
+                    let old = $RcBox::into_raw(this).as_ptr();
+                    unsafe {
+                        let layout = Layout::for_value::<T>(&*old);
+                        let new = if layout.size() == 0 {
+                            // NB: any aligned pointer is valid for a zero-sized box
+                            old as *mut u8
+                        } else {
+                            let new = alloc(layout);
+                            if new.is_null() {
+                                drop($Rc::from_raw(old));
+                                handle_alloc_error(layout)
+                            }
+                            ptr::copy_nonoverlapping(old as *const u8, new, layout.size());
+                            new
+                        };
+                        // Free the old allocation without dropping the moved value.
+                        drop($Rc::from_raw(old as *const ManuallyDrop<T>));
+                        Box::from_raw(set_ptr_value(old, new))
+                    }
+                }
+            }
+
+            doc_comment! {
+                concat!("Deconstruct this `", stringify!($RcBox), "`, returning the inner value."),
+                pub fn into_inner(this: Self) -> T
+                where
+                    T: Sized,
+                {
+                    let rc: $Rc<T> = this.into();
+                    $Rc::try_unwrap(rc).unwrap_or_else(|_| unsafe { unreachable_unchecked() })
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Convert this `", stringify!($RcBox), "` into a shared `", stringify!($Rc), "`,
+also returning a `Weak` reference to it.
+
+As the `", stringify!($RcBox), "` is unique, nothing can observe the `", stringify!($Rc), "`
+before the `Weak` reference exists.
+
+# Examples
+
+```rust
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+let unique = ", stringify!($RcBox), "::new(5);
+let (shared, weak) = ", stringify!($RcBox), "::", stringify!($into_rc_with_weak), "(unique);
+assert_eq!(", stringify!($Rc), "::weak_count(&shared), 1);
+assert_eq!(weak.upgrade().as_deref(), Some(&5));
+```"),
+                pub fn $into_rc_with_weak(this: Self) -> ($Rc<T>, $rc::Weak<T>) {
+                    let rc: $Rc<T> = this.into();
+                    let weak = $Rc::downgrade(&rc);
+                    (rc, weak)
+                }
+            }
+        }
+
+        impl<T> $RcBox<MaybeUninit<T>> {
+            doc_comment! {
+                concat!("\
+Convert to `", stringify!($RcBox), "<T>`.
+
+# Safety
+
+As with [`MaybeUninit::assume_init`], it is up to the caller to guarantee
+that the inner value really is in an initialized state.
+Calling this when the content is not yet fully initialized
+causes immediate undefined behavior."),
+                pub unsafe fn assume_init(self) -> $RcBox<T> {
+                    $RcBox::from_raw($RcBox::into_raw(self).cast::<T>().as_ptr())
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Write the value and convert to `", stringify!($RcBox), "<T>`.
+
+This is an associated function so that it doesn't shadow [`MaybeUninit::write`].
+
+# Examples
+
+```rust
+# use rc_box::*;
+let uninit = ", stringify!($RcBox), "::<u32>::new_uninit();
+let five = ", stringify!($RcBox), "::write(uninit, 5);
+assert_eq!(*five, 5);
+```"),
+                pub fn write(mut this: Self, value: T) -> $RcBox<T> {
+                    this.write(value);
+                    unsafe { this.assume_init() }
+                }
+            }
+        }
+
+        impl $RcBox<str> {
+            doc_comment! {
+                concat!("\
+Concatenate string slices into a single new ", stringify!($RcBox), ".
+
+# Examples
+
+```rust
+# use rc_box::*;
+let joined = ", stringify!($RcBox), "::<str>::concat(&[\"Hello\", \", \", \"world\"]);
+assert_eq!(&*joined, \"Hello, world\");
+```"),
+                pub fn concat(parts: &[&str]) -> Self {
+                    // NB: not generic over `AsRef<str>`, as an impl that isn't consistent
+                    // between calls could make us split a character.
+                    let bytes = $RcBox::concat_slices(parts.iter().map(|s| s.as_bytes()));
+                    unsafe { $RcBox::from_utf8_unchecked(bytes) }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Format a string directly into a new ", stringify!($RcBox), ".
+
+This formats the arguments twice: once to measure the length,
+and once to write the string into an exactly sized allocation.
+
+# Panics
+
+Panics if a formatting trait implementation returns an error,
+or formats differently the second time.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let name = \"world\";
+let greeting = ", stringify!($RcBox), "::from_fmt(format_args!(\"Hello, {}!\", name));
+assert_eq!(&*greeting, \"Hello, world!\");
+```"),
+                pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+                    const MSG: &str = "a formatting trait implementation returned an error unexpectedly";
+                    let mut counter = CountWriter(0);
+                    fmt::write(&mut counter, args).expect(MSG);
+                    let mut bytes = $RcBox::<[u8]>::new_uninit_slice(counter.0);
+                    let mut writer = SliceWriter { buf: &mut bytes, len: 0 };
+                    fmt::write(&mut writer, args).expect(MSG);
+                    assert_eq!(
+                        writer.len, counter.0,
+                        "a formatting trait implementation formatted differently the second time",
+                    );
+                    // SAFETY: the whole buffer has been filled with the concatenation of `str`s
+                    unsafe { $RcBox::from_utf8_unchecked(bytes.assume_init()) }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Convert a `", stringify!($RcBox), "<[u8]>` to a `", stringify!($RcBox), "<str>`,
+reusing the allocation.
+
+If the bytes are not valid UTF-8, the error holds the original box.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let bytes = ", stringify!($RcBox), "::<[u8]>::from(&b\"hello\"[..]);
+let string = ", stringify!($RcBox), "::from_utf8(bytes).unwrap();
+assert_eq!(&*string, \"hello\");
+
+let bytes = ", stringify!($RcBox), "::<[u8]>::from(&b\"\\xFF\"[..]);
+let error = ", stringify!($RcBox), "::from_utf8(bytes).unwrap_err();
+assert_eq!(**error.as_bytes(), [0xFF]);
+```"),
+                pub fn from_utf8(v: $RcBox<[u8]>) -> Result<Self, FromUtf8Error<$RcBox<[u8]>>> {
+                    match str::from_utf8(&v) {
+                        Ok(_) => unsafe { Ok($RcBox::from_utf8_unchecked(v)) },
+                        Err(error) => Err(FromUtf8Error { bytes: v, error }),
+                    }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Convert a `", stringify!($RcBox), "<[u8]>` to a `", stringify!($RcBox), "<str>`,
+reusing the allocation, without checking that the bytes are valid UTF-8.
+
+# Safety
+
+The bytes must be valid UTF-8."),
+                pub unsafe fn from_utf8_unchecked(v: $RcBox<[u8]>) -> Self {
+                    $RcBox::from_raw($RcBox::into_raw(v).as_ptr() as *const str)
+                }
+            }
+        }
+
+        impl<T> $RcBox<[T]> {
+            doc_comment! {
+                concat!("\
+Concatenate slices into a single new ", stringify!($RcBox), ".
+
+# Examples
+
+```rust
+# use rc_box::*;
+let joined = ", stringify!($RcBox), "::<[u8]>::concat(&[&b\"abc\"[..], b\"de\", b\"f\"]);
+assert_eq!(*joined, *b\"abcdef\");
+```"),
+                pub fn concat<S: AsRef<[T]>>(parts: &[S]) -> Self
+                where
+                    T: Clone,
+                {
+                    $RcBox::concat_slices(parts.iter().map(|s| s.as_ref()))
+                }
+            }
+
+            fn concat_slices<'a, I>(parts: I) -> Self
+            where
+                T: Clone + 'a,
+                I: Iterator<Item = &'a [T]> + Clone,
+            {
+                let len = parts
+                    .clone()
+                    .try_fold(0usize, |len, part| len.checked_add(part.len()))
+                    .expect("capacity overflow");
+                let items = parts.flat_map(|part| part.iter().cloned());
+                $RcBox::write_iter($RcBox::new_uninit_slice(len), items)
+            }
+
+            doc_comment! {
+                concat!("\
+Move the elements into a new `Vec`, freeing the reference counted allocation.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let values = ", stringify!($RcBox), "::from_array([1, 2, 3]);
+assert_eq!(", stringify!($RcBox), "::into_vec(values), vec![1, 2, 3]);
+```"),
+                pub fn into_vec(this: Self) -> Vec<T> {
+                    $RcBox::into_box(this).into_vec()
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " slice by moving an array into it.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let values = ", stringify!($RcBox), "::from_array([1, 2, 3]);
+assert_eq!(*values, [1, 2, 3]);
+```"),
+                pub fn from_array<const N: usize>(array: [T; N]) -> Self {
+                    $RcBox::from(array)
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " slice with uninitialized contents.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let mut values = ", stringify!($RcBox), "::<[u32]>::new_uninit_slice(3);
+values[0].write(1);
+values[1].write(2);
+values[2].write(3);
+let values = unsafe { values.assume_init() };
+assert_eq!(*values, [1, 2, 3]);
+```"),
+                pub fn new_uninit_slice(len: usize) -> $RcBox<[MaybeUninit<T>]> {
+                    // NB: this iterator is `TrustedLen`, so this allocates the exact size directly.
+                    (0..len).map(|_| MaybeUninit::uninit()).collect()
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " slice from an iterator of results,
+stopping at the first error.
+
+The elements are written directly into the allocation, using the length
+reported by the iterator; if an error is encountered, the elements already
+written are dropped and the allocation is freed.
+
+# Panics
+
+Panics if the iterator yields fewer items than its reported length.
+Any items beyond its reported length are not taken from the iterator.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let numbers = ", stringify!($RcBox), "::<[u32]>::try_from_iter([\"1\", \"2\", \"3\"].iter().map(|s| s.parse()));
+assert_eq!(*numbers.unwrap(), [1, 2, 3]);
+
+let numbers = ", stringify!($RcBox), "::<[u32]>::try_from_iter([\"1\", \"x\"].iter().map(|s| s.parse()));
+assert!(numbers.is_err());
+```"),
+                pub fn try_from_iter<I, E>(iter: I) -> Result<Self, E>
+                where
+                    I: IntoIterator<Item = Result<T, E>>,
+                    I::IntoIter: ExactSizeIterator,
+                {
+                    let iter = iter.into_iter();
+                    $RcBox::try_write_iter($RcBox::new_uninit_slice(iter.len()), iter)
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Construct a new ", stringify!($RcBox), " slice, initializing each element with `f(index)`.
+
+The elements are written directly into the allocation.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let squares = ", stringify!($RcBox), "::new_slice_with(4, |i| i * i);
+assert_eq!(*squares, [0, 1, 4, 9]);
+```"),
+                pub fn new_slice_with<F>(len: usize, f: F) -> Self
+                where
+                    F: FnMut(usize) -> T,
+                {
+                    $RcBox::write_iter($RcBox::new_uninit_slice(len), (0..len).map(f))
+                }
+            }
+        }
+
+        impl<T> $RcBox<[MaybeUninit<T>]> {
+            doc_comment! {
+                concat!("\
+Convert to `", stringify!($RcBox), "<[T]>`.
+
+# Safety
+
+As with [`MaybeUninit::assume_init`], it is up to the caller to guarantee
+that the inner values really are in an initialized state.
+Calling this when the content is not yet fully initialized
+causes immediate undefined behavior."),
+                pub unsafe fn assume_init(self) -> $RcBox<[T]> {
+                    $RcBox::from_raw($RcBox::into_raw(self).as_ptr() as *const [T])
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Fill the slice from an iterator and convert to `", stringify!($RcBox), "<[T]>`.
+
+Any items after the slice is filled are not taken from the iterator.
+
+# Panics
+
+Panics if the iterator yields fewer items than the length of the slice.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let uninit = ", stringify!($RcBox), "::<[u32]>::new_uninit_slice(3);
+let values = ", stringify!($RcBox), "::write_iter(uninit, 1..);
+assert_eq!(*values, [1, 2, 3]);
+```"),
+                pub fn write_iter<I>(this: Self, iter: I) -> $RcBox<[T]>
+                where
+                    I: IntoIterator<Item = T>,
+                {
+                    match $RcBox::try_write_iter(this, iter.into_iter().map(Ok::<T, Infallible>)) {
+                        Ok(this) => this,
+                        Err(never) => match never {},
+                    }
+                }
+            }
+
+            fn try_write_iter<I, E>(mut this: Self, iter: I) -> Result<$RcBox<[T]>, E>
+            where
+                I: IntoIterator<Item = Result<T, E>>,
+            {
+                let len = this.len();
+                let mut iter = iter.into_iter();
+                let mut guard = DropInitialized {
+                    ptr: this.as_mut_ptr().cast::<T>(),
+                    len: 0,
+                };
+                while guard.len < len {
+                    let item = iter
+                        .next()
+                        .expect("iterator yielded fewer items than the slice length")?;
+                    unsafe { guard.ptr.add(guard.len).write(item) };
+                    guard.len += 1;
                 }
+                mem::forget(guard);
+                unsafe { Ok(this.assume_init()) }
             }
         }
 
@@ -372,12 +1536,25 @@ then the data will be pinned in memory and unable to be moved."),
         }
 
         #[cfg(feature = "slice-dst")]
-        unsafe impl<S: ?Sized + SliceDst> AllocSliceDst<S> for $RcBox<S> {
-            unsafe fn new_slice_dst<I>(len: usize, init: I) -> Self
-            where
-                I: FnOnce(ptr::NonNull<S>),
-            {
-                Self::from_unchecked($Rc::new_slice_dst(len, init))
+        doc_comment! {
+            concat!("\
+Note that there is no such implementation for `Pin<", stringify!($RcBox), "<S>>`, as `", stringify!($Rc), "<S>`
+(and thus `", stringify!($RcBox), "<S>`) may initialize the slice DST in a `Box` and then move it.
+
+# Examples
+
+```rust
+# use {rc_box::*, slice_dst::SliceWithHeader};
+let slice: ", stringify!($RcBox), "<SliceWithHeader<u8, u32>> = SliceWithHeader::new(0, vec![1, 2]);
+assert_eq!(slice.slice, [1, 2]);
+```"),
+            unsafe impl<S: ?Sized + SliceDst> AllocSliceDst<S> for $RcBox<S> {
+                unsafe fn new_slice_dst<I>(len: usize, init: I) -> Self
+                where
+                    I: FnOnce(ptr::NonNull<S>),
+                {
+                    Self::from_unchecked($Rc::new_slice_dst(len, init))
+                }
             }
         }
         #[cfg(feature = "slice-dst")]
@@ -402,6 +1579,69 @@ then the data will be pinned in memory and unable to be moved."),
             }
         }
 
+        doc_comment! {
+            concat!("\
+View the string as bytes.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let s = ", stringify!($RcBox), "::<str>::from(\"hello\");
+let bytes: &[u8] = s.as_ref();
+assert_eq!(bytes, b\"hello\");
+```"),
+            impl AsRef<[u8]> for $RcBox<str> {
+                fn as_ref(&self) -> &[u8] {
+                    (**self).as_ref()
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        doc_comment! {
+            concat!("\
+View the string as an OS string.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::ffi::OsStr;
+
+let s = ", stringify!($RcBox), "::<str>::from(\"hello\");
+let os: &OsStr = s.as_ref();
+assert_eq!(os, \"hello\");
+```"),
+            impl AsRef<OsStr> for $RcBox<str> {
+                fn as_ref(&self) -> &OsStr {
+                    (**self).as_ref()
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        doc_comment! {
+            concat!("\
+View the string as a path.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::path::Path;
+
+let s = ", stringify!($RcBox), "::<str>::from(\"dir/file.txt\");
+let path: &Path = s.as_ref();
+assert_eq!(path.file_name().unwrap(), \"file.txt\");
+```"),
+            impl AsRef<Path> for $RcBox<str> {
+                fn as_ref(&self) -> &Path {
+                    (**self).as_ref()
+                }
+            }
+        }
+
         impl<T: ?Sized> Borrow<T> for $RcBox<T> {
             fn borrow(&self) -> &T {
                 &**self
@@ -414,7 +1654,111 @@ then the data will be pinned in memory and unable to be moved."),
             }
         }
 
-        // impl CoerceUnsized
+        #[cfg(feature = "std")]
+        impl<T: ?Sized> BufRead for $RcBox<T>
+        where
+            T: BufRead,
+        {
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                (**self).fill_buf()
+            }
+
+            fn consume(&mut self, amt: usize) {
+                (**self).consume(amt)
+            }
+
+            fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+                (**self).read_until(byte, buf)
+            }
+
+            fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+                (**self).read_line(buf)
+            }
+        }
+
+        doc_comment! {
+            concat!("\
+Clone the contained value into a new, unique allocation.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let a = ", stringify!($RcBox), "::new(vec![1, 2, 3]);
+let b = a.clone();
+assert_eq!(a, b);
+assert_ne!(", stringify!($RcBox), "::as_raw(&a), ", stringify!($RcBox), "::as_raw(&b));
+
+let s = ", stringify!($RcBox), "::<str>::from(\"hello\");
+assert_eq!(s.clone(), s);
+let v = ", stringify!($RcBox), "::<[i32]>::from([1, 2, 3]);
+assert_eq!(v.clone(), v);
+```"),
+            impl<T> Clone for $RcBox<T>
+            where
+                T: Clone,
+            {
+                fn clone(&self) -> Self {
+                    $RcBox::new((**self).clone())
+                }
+
+                fn clone_from(&mut self, source: &Self) {
+                    (**self).clone_from(&**source)
+                }
+            }
+        }
+
+        impl<T> Clone for $RcBox<[T]>
+        where
+            T: Clone,
+        {
+            fn clone(&self) -> Self {
+                $RcBox::from(&**self)
+            }
+        }
+
+        impl Clone for $RcBox<str> {
+            fn clone(&self) -> Self {
+                $RcBox::from(&**self)
+            }
+        }
+
+        #[cfg(feature = "coerce_unsized")]
+        doc_comment! {
+            concat!("Allows unsizing coercions, such as from `", stringify!($RcBox),
+"<T>` to `", stringify!($RcBox), "<dyn Trait>`.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::fmt::Display;
+
+let unique: ", stringify!($RcBox), "<dyn Display> = ", stringify!($RcBox), "::new(42);
+assert_eq!(unique.to_string(), \"42\");
+```
+
+Along with `DispatchFromDyn`, this also allows `", stringify!($RcBox), "<Self>`
+to be used as the receiver of a dyn-compatible method:
+
+```rust
+#![feature(arbitrary_self_types)]
+# use rc_box::*;
+trait Trait {
+    fn consume(self: ", stringify!($RcBox), "<Self>) -> u32;
+}
+
+impl Trait for u32 {
+    fn consume(self: ", stringify!($RcBox), "<Self>) -> u32 {
+        ", stringify!($RcBox), "::into_inner(self)
+    }
+}
+
+let unique: ", stringify!($RcBox), "<dyn Trait> = ", stringify!($RcBox), "::new(42);
+assert_eq!(unique.consume(), 42);
+```"),
+            impl<T: ?Sized, U: ?Sized> CoerceUnsized<$RcBox<U>> for $RcBox<T> where T: Unsize<U> {}
+        }
 
         impl<T: ?Sized> Debug for $RcBox<T>
         where
@@ -438,7 +1782,8 @@ then the data will be pinned in memory and unable to be moved."),
             }
         }
 
-        // impl DispatchFromDyn
+        #[cfg(feature = "coerce_unsized")]
+        impl<T: ?Sized, U: ?Sized> DispatchFromDyn<$RcBox<U>> for $RcBox<T> where T: Unsize<U> {}
 
         impl<T: ?Sized> Display for $RcBox<T>
         where
@@ -464,9 +1809,92 @@ then the data will be pinned in memory and unable to be moved."),
 
         impl<T: ?Sized> Eq for $RcBox<T> where T: Eq {}
 
+        #[cfg(any(has_core_error, feature = "std"))]
+        impl<T: ?Sized> Error for $RcBox<T>
+        where
+            T: Error,
+        {
+            #[allow(deprecated)]
+            fn description(&self) -> &str {
+                (**self).description()
+            }
+
+            #[allow(deprecated)]
+            fn cause(&self) -> Option<&dyn Error> {
+                (**self).cause()
+            }
+
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                (**self).source()
+            }
+        }
+
         impl<T: ?Sized> ExactSizeIterator for $RcBox<T> where T: ExactSizeIterator {}
 
-        // impl Fn, FnMut, FnOnce
+        // NB: unsized closures can't be called by value, so these are only for sized closures.
+        #[cfg(feature = "fn_traits")]
+        doc_comment! {
+            concat!("\
+Call the contained closure.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let add_one = ", stringify!($RcBox), "::new(|x: i32| x + 1);
+assert_eq!(add_one(1), 2);
+
+fn call_twice(mut f: impl FnMut()) {
+    f();
+    f();
+}
+let mut count = 0;
+call_twice(", stringify!($RcBox), "::new(|| count += 1));
+assert_eq!(count, 2);
+
+fn call_once(f: impl FnOnce() -> String) -> String {
+    f()
+}
+let s = String::from(\"hello\");
+assert_eq!(call_once(", stringify!($RcBox), "::new(move || s)), \"hello\");
+```"),
+            impl<Args: Tuple, F> Fn<Args> for $RcBox<F>
+            where
+                F: Fn<Args>,
+            {
+                extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+                    (**self).call(args)
+                }
+            }
+        }
+
+        #[cfg(feature = "fn_traits")]
+        impl<Args: Tuple, F> FnMut<Args> for $RcBox<F>
+        where
+            F: FnMut<Args>,
+        {
+            extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+                (**self).call_mut(args)
+            }
+        }
+
+        #[cfg(feature = "fn_traits")]
+        impl<Args: Tuple, F> FnOnce<Args> for $RcBox<F>
+        where
+            F: FnOnce<Args>,
+        {
+            type Output = F::Output;
+
+            extern "rust-call" fn call_once(self, args: Args) -> Self::Output {
+                $RcBox::into_inner(self).call_once(args)
+            }
+        }
+
+        impl<T, const N: usize> From<$RcBox<[T; N]>> for $RcBox<[T]> {
+            fn from(v: $RcBox<[T; N]>) -> Self {
+                unsafe { $RcBox::from_raw($RcBox::into_raw(v).as_ptr() as *const [T]) }
+            }
+        }
 
         impl<T> From<&'_ [T]> for $RcBox<[T]>
         where
@@ -483,6 +1911,144 @@ then the data will be pinned in memory and unable to be moved."),
             }
         }
 
+        doc_comment! {
+            concat!("\
+Copy a C string into a new box.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::ffi::{CStr, CString};
+
+let c = CString::new(\"hello\").unwrap();
+let a = ", stringify!($RcBox), "::<CStr>::from(&*c);
+let b = ", stringify!($RcBox), "::<CStr>::from(c);
+assert_eq!(a, b);
+assert_eq!(a.to_bytes(), b\"hello\");
+```"),
+            impl From<&'_ CStr> for $RcBox<CStr> {
+                fn from(v: &CStr) -> Self {
+                    unsafe { $RcBox::from_unchecked($Rc::from(v)) }
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        doc_comment! {
+            concat!("\
+Copy an OS string into a new box.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::ffi::{OsStr, OsString};
+
+let a = ", stringify!($RcBox), "::<OsStr>::from(OsStr::new(\"hello\"));
+let b = ", stringify!($RcBox), "::<OsStr>::from(OsString::from(\"hello\"));
+assert_eq!(a, b);
+assert_eq!(*a, *\"hello\");
+```"),
+            impl From<&'_ OsStr> for $RcBox<OsStr> {
+                fn from(v: &OsStr) -> Self {
+                    unsafe { $RcBox::from_unchecked($Rc::from(v)) }
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        doc_comment! {
+            concat!("\
+Copy a path into a new box.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::path::{Path, PathBuf};
+
+let a = ", stringify!($RcBox), "::<Path>::from(Path::new(\"dir/file.txt\"));
+let b = ", stringify!($RcBox), "::<Path>::from(PathBuf::from(\"dir/file.txt\"));
+assert_eq!(a, b);
+assert_eq!(a.file_name().unwrap(), \"file.txt\");
+```"),
+            impl From<&'_ Path> for $RcBox<Path> {
+                fn from(v: &Path) -> Self {
+                    unsafe { $RcBox::from_unchecked($Rc::from(v)) }
+                }
+            }
+        }
+
+        impl From<CString> for $RcBox<CStr> {
+            fn from(v: CString) -> Self {
+                unsafe { $RcBox::from_unchecked($Rc::from(v)) }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl From<OsString> for $RcBox<OsStr> {
+            fn from(v: OsString) -> Self {
+                unsafe { $RcBox::from_unchecked($Rc::from(v)) }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl From<PathBuf> for $RcBox<Path> {
+            fn from(v: PathBuf) -> Self {
+                unsafe { $RcBox::from_unchecked($Rc::from(v)) }
+            }
+        }
+
+        doc_comment! {
+            concat!("\
+Copy borrowed data, or move owned data, into a new box.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::borrow::Cow;
+
+let borrowed: Cow<'_, str> = Cow::Borrowed(\"hello\");
+let owned: Cow<'_, str> = Cow::Owned(String::from(\"hello\"));
+assert_eq!(", stringify!($RcBox), "::<str>::from(borrowed), ", stringify!($RcBox), "::<str>::from(owned));
+
+let slice: Cow<'_, [i32]> = Cow::Borrowed(&[1, 2, 3]);
+assert_eq!(*", stringify!($RcBox), "::<[i32]>::from(slice), [1, 2, 3]);
+```"),
+            impl<'a, B: ?Sized> From<Cow<'a, B>> for $RcBox<B>
+            where
+                B: ToOwned,
+                $RcBox<B>: From<&'a B> + From<B::Owned>,
+            {
+                fn from(v: Cow<'a, B>) -> Self {
+                    match v {
+                        Cow::Borrowed(v) => $RcBox::from(v),
+                        Cow::Owned(v) => $RcBox::from(v),
+                    }
+                }
+            }
+        }
+
+        impl From<$RcBox<str>> for $RcBox<[u8]> {
+            fn from(v: $RcBox<str>) -> Self {
+                unsafe { $RcBox::from_raw($RcBox::into_raw(v).as_ptr() as *const [u8]) }
+            }
+        }
+
+        impl<T, const N: usize> From<[T; N]> for $RcBox<[T]> {
+            fn from(v: [T; N]) -> Self {
+                $RcBox::<[T; N]>::new(v).into()
+            }
+        }
+
+        impl<T: ?Sized> From<$RcBox<T>> for Pin<$RcBox<T>> {
+            fn from(v: $RcBox<T>) -> Self {
+                $RcBox::into_pin(v)
+            }
+        }
+
         impl<T: ?Sized> From<Box<T>> for $RcBox<T> {
             fn from(v: Box<T>) -> Self {
                 unsafe { $RcBox::from_unchecked($Rc::from(v)) }
@@ -513,10 +2079,57 @@ then the data will be pinned in memory and unable to be moved."),
             }
         }
 
-        impl<T: ?Sized> FusedIterator for $RcBox<T> where T: FusedIterator {}
-
-        // Skip Future/Generator; just use Box instead! There's no reason to share it later.
-
+        doc_comment! {
+            concat!("\
+Parse a string into a new box, by copying it. This never fails.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let s: ", stringify!($RcBox), "<str> = \"hello\".parse().unwrap();
+assert_eq!(*s, *\"hello\");
+```"),
+            impl FromStr for $RcBox<str> {
+                type Err = Infallible;
+                fn from_str(s: &str) -> Result<Self, Infallible> {
+                    Ok($RcBox::from(s))
+                }
+            }
+        }
+
+        impl<T: ?Sized> FusedIterator for $RcBox<T> where T: FusedIterator {}
+
+        // NB: `Pin<$RcBox<F>>` is already a `Future` for `F: !Unpin` via `impl Future for Pin<P>`.
+        doc_comment! {
+            concat!("\
+Poll the contained future.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::{future::{self, Future}, pin::Pin, task::{Context, Poll}};
+# use std::{ptr, task::{RawWaker, RawWakerVTable, Waker}};
+# const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| {}, |_| {}, |_| {});
+# const RAW: RawWaker = RawWaker::new(ptr::null(), &VTABLE);
+# let waker = unsafe { Waker::from_raw(RAW) };
+# let mut cx = Context::from_waker(&waker);
+let mut fut = ", stringify!($RcBox), "::new(future::ready(5));
+assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(5));
+```"),
+            impl<F: ?Sized> Future for $RcBox<F>
+            where
+                F: Future + Unpin,
+            {
+                type Output = F::Output;
+
+                fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                    F::poll(Pin::new(&mut **self), cx)
+                }
+            }
+        }
+
         impl<T: ?Sized> Hash for $RcBox<T>
         where
             T: Hash,
@@ -641,14 +2254,276 @@ then the data will be pinned in memory and unable to be moved."),
             }
         }
 
-        // impl TryFrom<($Rc)(Box)<[T]>> for $RcBox<[T; N]>
-        // (waiting on const generics)
+        #[cfg(feature = "std")]
+        doc_comment! {
+            concat!("\
+Read from the contained reader.
+
+The `Write`, `Seek`, and `BufRead` impls likewise forward to the contained value.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::io::{BufRead, Cursor, Read, Seek, SeekFrom, Write};
+
+let mut file = ", stringify!($RcBox), "::new(Cursor::new(Vec::new()));
+file.write_all(b\"hello\\nworld\").unwrap();
+file.seek(SeekFrom::Start(0)).unwrap();
+
+let mut line = String::new();
+file.read_line(&mut line).unwrap();
+assert_eq!(line, \"hello\\n\");
+let mut rest = String::new();
+file.read_to_string(&mut rest).unwrap();
+assert_eq!(rest, \"world\");
+```"),
+            impl<T: ?Sized> Read for $RcBox<T>
+            where
+                T: Read,
+            {
+                fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                    (**self).read(buf)
+                }
+
+                fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+                    (**self).read_vectored(bufs)
+                }
+
+                fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+                    (**self).read_to_end(buf)
+                }
+
+                fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+                    (**self).read_to_string(buf)
+                }
+
+                fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+                    (**self).read_exact(buf)
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<T: ?Sized> Seek for $RcBox<T>
+        where
+            T: Seek,
+        {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                (**self).seek(pos)
+            }
+
+            fn stream_position(&mut self) -> io::Result<u64> {
+                (**self).stream_position()
+            }
+        }
+
+        #[cfg(feature = "futures")]
+        doc_comment! {
+            concat!("\
+Send items into the contained sink.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use futures_sink::Sink;
+use std::{convert::Infallible, pin::Pin, task::{Context, Poll}};
+# use std::{ptr, task::{RawWaker, RawWakerVTable, Waker}};
+# const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| {}, |_| {}, |_| {});
+# const RAW: RawWaker = RawWaker::new(ptr::null(), &VTABLE);
+# let waker = unsafe { Waker::from_raw(RAW) };
+# let mut cx = Context::from_waker(&waker);
+
+struct Collect(Vec<i32>);
+impl Sink<i32> for Collect {
+    type Error = Infallible;
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+    fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), Infallible> {
+        self.0.push(item);
+        Ok(())
+    }
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+let mut sink = ", stringify!($RcBox), "::new(Collect(Vec::new()));
+assert_eq!(Pin::new(&mut sink).poll_ready(&mut cx), Poll::Ready(Ok(())));
+Pin::new(&mut sink).start_send(1).unwrap();
+assert_eq!(Pin::new(&mut sink).poll_close(&mut cx), Poll::Ready(Ok(())));
+assert_eq!(sink.0, [1]);
+```"),
+            impl<S: ?Sized, Item> futures_sink::Sink<Item> for $RcBox<S>
+            where
+                S: futures_sink::Sink<Item> + Unpin,
+            {
+                type Error = S::Error;
+
+                fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                    Pin::new(&mut **self).poll_ready(cx)
+                }
+
+                fn start_send(mut self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+                    Pin::new(&mut **self).start_send(item)
+                }
+
+                fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                    Pin::new(&mut **self).poll_flush(cx)
+                }
+
+                fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                    Pin::new(&mut **self).poll_close(cx)
+                }
+            }
+        }
+
+        #[cfg(feature = "futures")]
+        doc_comment! {
+            concat!("\
+Poll the contained stream.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use futures_core::Stream;
+use std::{pin::Pin, task::{Context, Poll}};
+# use std::{ptr, task::{RawWaker, RawWakerVTable, Waker}};
+# const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| {}, |_| {}, |_| {});
+# const RAW: RawWaker = RawWaker::new(ptr::null(), &VTABLE);
+# let waker = unsafe { Waker::from_raw(RAW) };
+# let mut cx = Context::from_waker(&waker);
+
+struct Countdown(u32);
+impl Stream for Countdown {
+    type Item = u32;
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.0 == 0 {
+            return Poll::Ready(None);
+        }
+        self.0 -= 1;
+        Poll::Ready(Some(self.0))
+    }
+}
+
+let mut stream = ", stringify!($RcBox), "::new(Countdown(2));
+assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(1)));
+assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(0)));
+assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+```"),
+            impl<S: ?Sized> futures_core::Stream for $RcBox<S>
+            where
+                S: futures_core::Stream + Unpin,
+            {
+                type Item = S::Item;
+
+                fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                    Pin::new(&mut **self).poll_next(cx)
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (**self).size_hint()
+                }
+            }
+        }
+
+        doc_comment! {
+            concat!("\
+Convert a slice box into an array box, if the length matches.
+Otherwise, the slice box is returned unchanged.
+
+# Examples
+
+```rust
+# use rc_box::*;
+let slice = ", stringify!($RcBox), "::from_array([1, 2, 3]);
+let slice = ", stringify!($RcBox), "::<[i32; 4]>::try_from(slice).unwrap_err();
+assert_eq!(*slice, [1, 2, 3]);
+let array = ", stringify!($RcBox), "::<[i32; 3]>::try_from(slice).unwrap();
+assert_eq!(*array, [1, 2, 3]);
+```"),
+            impl<T, const N: usize> TryFrom<$RcBox<[T]>> for $RcBox<[T; N]> {
+                type Error = $RcBox<[T]>;
+                fn try_from(v: $RcBox<[T]>) -> Result<$RcBox<[T; N]>, $RcBox<[T]>> {
+                    if v.len() == N {
+                        unsafe { Ok($RcBox::from_raw($RcBox::into_raw(v).as_ptr() as *const [T; N])) }
+                    } else {
+                        Err(v)
+                    }
+                }
+            }
+        }
 
         impl<T: ?Sized> Unpin for $RcBox<T> {}
 
         #[cfg(feature = "std")]
         impl<T: ?Sized> UnwindSafe for $RcBox<T> where Box<T>: UnwindSafe {}
 
+        #[cfg(feature = "std")]
+        impl<T: ?Sized> Write for $RcBox<T>
+        where
+            T: Write,
+        {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                (**self).write(buf)
+            }
+
+            fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+                (**self).write_vectored(bufs)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                (**self).flush()
+            }
+
+            fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+                (**self).write_all(buf)
+            }
+
+            fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> io::Result<()> {
+                (**self).write_fmt(fmt)
+            }
+        }
+
+        doc_comment! {
+            concat!("\
+Write to the contained writer.
+
+# Examples
+
+```rust
+# use rc_box::*;
+use std::fmt::Write;
+
+let mut s = ", stringify!($RcBox), "::new(String::new());
+write!(s, \"{} {}\", \"hello\", 1).unwrap();
+s.write_char('!').unwrap();
+assert_eq!(*s, \"hello 1!\");
+```"),
+            impl<T: ?Sized> fmt::Write for $RcBox<T>
+            where
+                T: fmt::Write,
+            {
+                fn write_str(&mut self, s: &str) -> fmt::Result {
+                    (**self).write_str(s)
+                }
+
+                fn write_char(&mut self, c: char) -> fmt::Result {
+                    (**self).write_char(c)
+                }
+
+                fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+                    (**self).write_fmt(args)
+                }
+            }
+        }
+
         #[cfg(feature = "unsize")]
         doc_comment! {
             concat!("Unsizes a pointer using the `unsize` crate.
@@ -696,17 +2571,307 @@ print_if_string(my_number);
     )*};
 }
 
+/// The error returned when a shared pointer is not unique.
+///
+/// This is returned by [`RcBox::try_from_with_counts`] and [`ArcBox::try_from_with_counts`],
+/// and holds the original pointer so that it isn't lost.
+///
+/// For `Arc`, the counts are only a snapshot, and may have changed since.
+#[derive(Debug, Clone)]
+pub struct NotUniqueError<P> {
+    ptr: P,
+    strong_count: usize,
+    weak_count: usize,
+}
+
+impl<P> NotUniqueError<P> {
+    /// Returns a reference to the pointer that was attempted to convert.
+    pub fn as_inner(&self) -> &P {
+        &self.ptr
+    }
+
+    /// Returns the pointer that was attempted to convert.
+    pub fn into_inner(self) -> P {
+        self.ptr
+    }
+
+    /// The number of strong pointers observed when the conversion failed.
+    pub fn strong_count(&self) -> usize {
+        self.strong_count
+    }
+
+    /// The number of weak pointers observed when the conversion failed.
+    pub fn weak_count(&self) -> usize {
+        self.weak_count
+    }
+}
+
+impl<P> Display for NotUniqueError<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pointer is not unique ({} strong and {} weak references)",
+            self.strong_count, self.weak_count,
+        )
+    }
+}
+
+#[cfg(any(has_core_error, feature = "std"))]
+impl<P: Debug> Error for NotUniqueError<P> {}
+
+/// A possible error value when converting a boxed byte slice to a boxed `str`.
+///
+/// This is returned by [`RcBox::from_utf8`] and [`ArcBox::from_utf8`],
+/// and holds the original byte box so that it isn't lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromUtf8Error<B> {
+    bytes: B,
+    error: Utf8Error,
+}
+
+impl<B> FromUtf8Error<B> {
+    /// Returns a reference to the byte box that was attempted to convert.
+    pub fn as_bytes(&self) -> &B {
+        &self.bytes
+    }
+
+    /// Returns the byte box that was attempted to convert.
+    pub fn into_bytes(self) -> B {
+        self.bytes
+    }
+
+    /// Fetch a `Utf8Error` to get more details about the conversion failure.
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl<B> Display for FromUtf8Error<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(any(has_core_error, feature = "std"))]
+impl<B: Debug> Error for FromUtf8Error<B> {}
+
 rc_box! {
     /// Known unique version of [`Arc`].
     ///
     /// This type is guaranteed to have the same repr as `Box<T>`.
     /// (The heap layout is that of `Arc<T>`.)
+    ///
+    /// With the nightly `dropck_eyepatch` feature enabled, dropping an `ArcBox<T>`
+    /// does not require borrows held by `T` to be live, just like `Box<T>`.
+    /// This allows e.g. arena-style nodes that point at each other:
+    ///
+    #[cfg_attr(feature = "dropck_eyepatch", doc = "```rust")]
+    #[cfg_attr(not(feature = "dropck_eyepatch"), doc = "```rust,compile_fail,E0597")]
+    /// # use rc_box::*;
+    /// use std::cell::Cell;
+    ///
+    /// struct Node<'arena> {
+    ///     next: Cell<Option<&'arena Node<'arena>>>,
+    /// }
+    ///
+    /// let (a, b): (ArcBox<Node<'_>>, ArcBox<Node<'_>>);
+    /// a = ArcBox::new(Node { next: Cell::new(None) });
+    /// b = ArcBox::new(Node { next: Cell::new(None) });
+    /// a.next.set(Some(&b));
+    /// b.next.set(Some(&a));
+    /// ```
     #[repr(transparent)]
-    ArcBox = Arc
+    ArcBox, ArcBoxGuard = Arc in sync { from_arc_unchecked, into_pin_arc, into_arc_with_weak }
     /// Known unique version of [`Rc`].
     ///
     /// This type is guaranteed to have the same repr as `Box<T>`.
     /// (The heap layout is that of `Rc<T>`.)
     #[repr(transparent)]
-    RcBox = Rc
+    RcBox, RcBoxGuard = Rc in rc { from_rc_unchecked, into_pin_rc, into_rc_with_weak }
+}
+
+#[cfg(feature = "allocator_api")]
+macro_rules! rc_box_in {
+    ($($(#[$m:meta])* $RcBoxIn:ident = $RcBox:ident, $Rc:ident;)*) => {$(
+        $(#[$m])*
+        pub struct $RcBoxIn<T: ?Sized, A: Allocator = Global> {
+            raw: ptr::NonNull<T>,
+            alloc: A,
+            marker: PhantomData<$Rc<T, A>>,
+        }
+
+        unsafe impl<T: ?Sized, A: Allocator> Send for $RcBoxIn<T, A> where Box<T, A>: Send {}
+        unsafe impl<T: ?Sized, A: Allocator> Sync for $RcBoxIn<T, A> where Box<T, A>: Sync {}
+
+        #[cfg(not(feature = "dropck_eyepatch"))]
+        impl<T: ?Sized, A: Allocator> Drop for $RcBoxIn<T, A> {
+            fn drop(&mut self) {
+                unsafe { drop($Rc::<T, A>::from(ptr::read(self))) }
+            }
+        }
+
+        // NB: the `PhantomData<$Rc<T, A>>` marker tells dropck that we drop a `T`.
+        #[cfg(feature = "dropck_eyepatch")]
+        unsafe impl<#[may_dangle] T: ?Sized, A: Allocator> Drop for $RcBoxIn<T, A> {
+            fn drop(&mut self) {
+                unsafe { drop($Rc::<T, A>::from(ptr::read(self))) }
+            }
+        }
+
+        impl<T: ?Sized, A: Allocator> From<$RcBoxIn<T, A>> for $Rc<T, A> {
+            fn from(v: $RcBoxIn<T, A>) -> $Rc<T, A> {
+                let (raw, alloc) = $RcBoxIn::into_raw_with_allocator(v);
+                unsafe { $Rc::from_raw_in(raw.as_ptr(), alloc) }
+            }
+        }
+
+        impl<T: ?Sized, A: Allocator> TryFrom<$Rc<T, A>> for $RcBoxIn<T, A> {
+            type Error = $Rc<T, A>;
+            fn try_from(mut v: $Rc<T, A>) -> Result<$RcBoxIn<T, A>, $Rc<T, A>> {
+                if $Rc::get_mut(&mut v).is_some() {
+                    let (raw, alloc) = $Rc::into_raw_with_allocator(v);
+                    unsafe { Ok($RcBoxIn::from_raw_in(raw, alloc)) }
+                } else {
+                    Err(v)
+                }
+            }
+        }
+
+        impl<T: ?Sized> From<$RcBox<T>> for $RcBoxIn<T> {
+            fn from(v: $RcBox<T>) -> $RcBoxIn<T> {
+                unsafe { $RcBoxIn::from_raw_in($RcBox::into_raw(v).as_ptr(), Global) }
+            }
+        }
+
+        impl<T: ?Sized> From<$RcBoxIn<T>> for $RcBox<T> {
+            fn from(v: $RcBoxIn<T>) -> $RcBox<T> {
+                let (raw, Global) = $RcBoxIn::into_raw_with_allocator(v);
+                unsafe { $RcBox::from_raw(raw.as_ptr()) }
+            }
+        }
+
+        impl<T: ?Sized, A: Allocator> $RcBoxIn<T, A> {
+            doc_comment! {
+                concat!("Returns a reference to the underlying allocator."),
+                pub fn allocator(this: &Self) -> &A {
+                    &this.alloc
+                }
+            }
+
+            doc_comment! {
+                concat!("Construct an ", stringify!($RcBoxIn), " from a raw pointer in the given allocator.
+
+# Safety
+
+The raw pointer must have previously been acquired by a call to [`",
+stringify!($RcBoxIn), "::into_raw_with_allocator`], or [`", stringify!($Rc), "::into_raw_with_allocator`]
+where the `", stringify!($Rc), "` is known unique, and `alloc` must be the same allocator."),
+                pub unsafe fn from_raw_in(ptr: *const T, alloc: A) -> Self {
+                    $RcBoxIn {
+                        raw: ptr::NonNull::new_unchecked(ptr as *mut _),
+                        alloc,
+                        marker: PhantomData,
+                    }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Consume the `", stringify!($RcBoxIn), "`, returning the wrapped pointer and the allocator.
+
+To avoid a memory leak, the pointer must be converted back to a `",
+stringify!($RcBoxIn), "`, using [`", stringify!($RcBoxIn), "::from_raw_in`],
+or directly into a `", stringify!($Rc), "`, using [`", stringify!($Rc), "::from_raw_in`]."),
+                pub fn into_raw_with_allocator(this: Self) -> (ptr::NonNull<T>, A) {
+                    let this = ManuallyDrop::new(this);
+                    (this.raw, unsafe { ptr::read(&this.alloc) })
+                }
+            }
+
+            doc_comment! {
+                concat!("Create a new ", stringify!($RcBoxIn), " in the given allocator.
+
+# Examples
+
+```rust
+#![feature(allocator_api)]
+# use rc_box::*;
+# use std::rc::Rc; use std::sync::Arc;
+use std::alloc::System;
+
+let mut five = ", stringify!($RcBoxIn), "::new_in(5, System);
+*five += 1;
+let six: ", stringify!($Rc), "<i32, System> = five.into();
+assert_eq!(*six, 6);
+```"),
+                pub fn new_in(data: T, alloc: A) -> Self
+                where
+                    T: Sized,
+                {
+                    let (raw, alloc) = $Rc::into_raw_with_allocator($Rc::new_in(data, alloc));
+                    unsafe { $RcBoxIn::from_raw_in(raw, alloc) }
+                }
+            }
+
+            doc_comment! {
+                concat!("\
+Create a new ", stringify!($RcBoxIn), " in the given allocator,
+returning an error if the allocation fails."),
+                pub fn try_new_in(data: T, alloc: A) -> Result<Self, AllocError>
+                where
+                    T: Sized,
+                {
+                    let (raw, alloc) = $Rc::into_raw_with_allocator($Rc::try_new_in(data, alloc)?);
+                    unsafe { Ok($RcBoxIn::from_raw_in(raw, alloc)) }
+                }
+            }
+        }
+
+        impl<T: ?Sized, A: Allocator> Debug for $RcBoxIn<T, A>
+        where
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<T: ?Sized, A: Allocator> Deref for $RcBoxIn<T, A> {
+            type Target = T;
+            fn deref(&self) -> &T {
+                unsafe { self.raw.as_ref() }
+            }
+        }
+
+        impl<T: ?Sized, A: Allocator> DerefMut for $RcBoxIn<T, A> {
+            fn deref_mut(&mut self) -> &mut T {
+                unsafe { self.raw.as_mut() }
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "allocator_api")]
+rc_box_in! {
+    /// Known unique version of [`Arc`] in a custom allocator.
+    ///
+    /// Unlike [`ArcBox`], this stores the allocator inline,
+    /// so it doesn't have the same repr as `Box<T>`.
+    /// `ArcBoxIn<T>` (using the `Global` allocator) converts to and from `ArcBox<T>` for free.
+    ///
+    /// ```rust
+    /// #![feature(allocator_api)]
+    /// # use rc_box::*;
+    /// let boxed: ArcBoxIn<i32> = ArcBox::new(5).into();
+    /// let boxed: ArcBox<i32> = boxed.into();
+    /// assert_eq!(*boxed, 5);
+    /// assert_eq!(std::mem::size_of::<ArcBox<i32>>(), std::mem::size_of::<Box<i32>>());
+    /// ```
+    ArcBoxIn = ArcBox, Arc;
+    /// Known unique version of [`Rc`] in a custom allocator.
+    ///
+    /// Unlike [`RcBox`], this stores the allocator inline,
+    /// so it doesn't have the same repr as `Box<T>`.
+    /// `RcBoxIn<T>` (using the `Global` allocator) converts to and from `RcBox<T>` for free.
+    RcBoxIn = RcBox, Rc;
 }
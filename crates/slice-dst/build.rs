@@ -1,7 +1,13 @@
 fn main() {
     let cfg = autocfg::new();
-    println!("cargo::rustc-check-cfg=cfg(has_strict_provenance)");
+    println!("cargo::rustc-check-cfg=cfg(has_core_error, has_new_uninit, has_strict_provenance)");
     cfg.emit_type_cfg("!", "has_never");
+    if cfg.probe_rustc_version(1, 81) {
+        autocfg::emit("has_core_error");
+    }
+    if cfg.probe_rustc_version(1, 82) {
+        autocfg::emit("has_new_uninit");
+    }
     cfg.emit_expression_cfg("<*const ()>::addr", "has_strict_provenance");
     autocfg::rerun_path("build.rs");
 }
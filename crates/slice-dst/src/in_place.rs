@@ -0,0 +1,102 @@
+//! In-place construction of slice DSTs in `Rc` and `Arc`.
+//!
+//! The standard library doesn't provide a way to allocate an `Rc<S>` for an
+//! arbitrary layout, but it does let us allocate an uninitialized `Rc<[T]>`.
+//! If `T` has the same alignment as `S`, and the slice the same size as `S`,
+//! then the `Rc<[T]>` allocation has exactly the layout an `Rc<S>` needs,
+//! and `Rc::from_raw` allows us to cast between the two.
+//!
+//! So we provide a chunk type for each (reasonable) alignment, and check the
+//! layout at runtime. If the layout isn't supported, we fall back to
+//! initializing in a `Box` and moving into the `Rc`.
+
+#[cfg(has_new_uninit)]
+use core::{alloc::Layout, mem, mem::MaybeUninit};
+use {
+    crate::{SliceDst, TryAllocSliceDst},
+    alloc::{boxed::Box, rc::Rc, sync::Arc},
+    core::ptr,
+};
+
+macro_rules! chunks {
+    ($($Chunk:ident = $align:literal),* $(,)?) => {$(
+        #[cfg(has_new_uninit)]
+        #[derive(Clone, Copy)]
+        #[repr(C, align($align))]
+        struct $Chunk([u8; $align]);
+    )*};
+}
+
+chunks! {
+    Align1 = 1,
+    Align2 = 2,
+    Align4 = 4,
+    Align8 = 8,
+    Align16 = 16,
+    Align32 = 32,
+    Align64 = 64,
+}
+
+macro_rules! in_place {
+    ($($Rc:ident: $try_new_rc:ident;)*) => {$(
+        pub(crate) unsafe fn $try_new_rc<S: ?Sized + SliceDst, I, E>(
+            len: usize,
+            init: I,
+        ) -> Result<$Rc<S>, E>
+        where
+            I: FnOnce(ptr::NonNull<S>) -> Result<(), E>,
+        {
+            #[cfg(has_new_uninit)]
+            #[allow(clippy::incompatible_msrv)] // gated on has_new_uninit
+            unsafe fn in_chunks<C: Copy, S: ?Sized + SliceDst, I, E>(
+                layout: Layout,
+                len: usize,
+                init: I,
+            ) -> Result<$Rc<S>, E>
+            where
+                I: FnOnce(ptr::NonNull<S>) -> Result<(), E>,
+            {
+                // deallocates (without dropping) if init fails or panics
+                struct Guard<C>(*const [MaybeUninit<C>]);
+                impl<C> Drop for Guard<C> {
+                    fn drop(&mut self) {
+                        unsafe { drop($Rc::from_raw(self.0)) }
+                    }
+                }
+
+                let chunks = layout.size() / mem::size_of::<C>();
+                let guard = Guard($Rc::into_raw($Rc::<[C]>::new_uninit_slice(chunks)));
+                let raw = ptr::slice_from_raw_parts_mut(guard.0 as *mut (), len);
+                let ptr = S::retype(ptr::NonNull::new_unchecked(raw));
+                init(ptr)?;
+                debug_assert_eq!(Layout::for_value(ptr.as_ref()), layout);
+                mem::forget(guard);
+                Ok($Rc::from_raw(ptr.as_ptr()))
+            }
+
+            #[cfg(has_new_uninit)]
+            {
+                let layout = S::layout_for(len);
+                // NB: the slice of chunks must be exactly the size of S
+                if layout.pad_to_align() == layout {
+                    match layout.align() {
+                        1 => return in_chunks::<Align1, S, I, E>(layout, len, init),
+                        2 => return in_chunks::<Align2, S, I, E>(layout, len, init),
+                        4 => return in_chunks::<Align4, S, I, E>(layout, len, init),
+                        8 => return in_chunks::<Align8, S, I, E>(layout, len, init),
+                        16 => return in_chunks::<Align16, S, I, E>(layout, len, init),
+                        32 => return in_chunks::<Align32, S, I, E>(layout, len, init),
+                        64 => return in_chunks::<Align64, S, I, E>(layout, len, init),
+                        _ => {}
+                    }
+                }
+            }
+            Box::try_new_slice_dst(len, init).map(Into::into)
+        }
+    )*};
+}
+
+in_place! {
+    Rc: try_new_rc;
+    Arc: try_new_arc;
+}
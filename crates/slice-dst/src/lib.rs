@@ -1,5 +1,6 @@
 #![warn(missing_docs, missing_debug_implementations)]
 #![no_std]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 //! Support for custom slice-based DSTs.
 //!
@@ -138,16 +139,28 @@
 
 extern crate alloc;
 
+#[cfg(not(feature = "allocator_api"))]
+use alloc::alloc::dealloc;
+#[cfg(has_core_error)]
+use core::error::Error;
 #[cfg(feature = "erasable")]
 use erasable::{Erasable, ErasedPtr};
+#[cfg(feature = "allocator_api")]
+use {alloc::alloc::Global, core::alloc::Allocator};
 use {
     alloc::{
-        alloc::{alloc, dealloc, handle_alloc_error},
+        alloc::{alloc, handle_alloc_error},
         boxed::Box,
         rc::Rc,
         sync::Arc,
     },
-    core::{alloc::Layout, mem::ManuallyDrop, ptr},
+    core::{
+        alloc::{Layout, LayoutError},
+        fmt,
+        mem::ManuallyDrop,
+        pin::Pin,
+        ptr,
+    },
 };
 
 /// A custom slice-based dynamically sized type.
@@ -162,6 +175,15 @@ pub unsafe trait SliceDst {
     /// Get the layout of the slice-containing type with the given slice length.
     fn layout_for(len: usize) -> Layout;
 
+    /// Get the layout of the slice-containing type with the given slice length,
+    /// or an error if the layout would overflow.
+    ///
+    /// The default implementation defers to [`SliceDst::layout_for`],
+    /// so may panic instead of returning an error.
+    fn try_layout_for(len: usize) -> Result<Layout, LayoutError> {
+        Ok(Self::layout_for(len))
+    }
+
     /// Add the type onto an untyped pointer.
     ///
     /// This is used to add the type on during allocation.
@@ -182,7 +204,11 @@ pub unsafe trait SliceDst {
 
 unsafe impl<T> SliceDst for [T] {
     fn layout_for(len: usize) -> Layout {
-        Layout::array::<T>(len).unwrap()
+        Self::try_layout_for(len).unwrap()
+    }
+
+    fn try_layout_for(len: usize) -> Result<Layout, LayoutError> {
+        Layout::array::<T>(len)
     }
 
     fn retype(ptr: ptr::NonNull<[()]>) -> ptr::NonNull<Self> {
@@ -216,7 +242,76 @@ pub fn alloc_slice_dst_in<S: ?Sized + SliceDst, F>(container: F, len: usize) ->
 where
     F: FnOnce(Layout) -> Layout,
 {
-    let layout = container(S::layout_for(len));
+    try_alloc_slice_dst_in(container, len).unwrap_or_else(|err| err.handle())
+}
+
+/// The error returned by fallible slice DST allocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TryAllocError {
+    /// The layout of the slice DST overflowed.
+    Layout(LayoutError),
+    /// The allocator failed to allocate memory for the layout.
+    Alloc(Layout),
+}
+
+impl TryAllocError {
+    /// Panic or abort like the infallible allocation functions do.
+    fn handle(self) -> ! {
+        match self {
+            TryAllocError::Layout(err) => panic!("slice DST layout overflowed: {}", err),
+            TryAllocError::Alloc(layout) => handle_alloc_error(layout),
+        }
+    }
+}
+
+impl fmt::Display for TryAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryAllocError::Layout(_) => f.write_str("slice DST layout overflowed"),
+            TryAllocError::Alloc(layout) => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+#[cfg(has_core_error)]
+impl Error for TryAllocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TryAllocError::Layout(err) => Some(err),
+            TryAllocError::Alloc(_) => None,
+        }
+    }
+}
+
+/// Allocate a slice-based DST with the [global allocator][`alloc()`],
+/// returning an error instead of panicking or aborting on failure.
+///
+/// The returned pointer is owned and completely uninitialized;
+/// you are required to initialize it correctly.
+///
+/// If the type to be allocated has zero size,
+/// then an arbitrary aligned dangling nonnull pointer is returned.
+pub fn try_alloc_slice_dst<S: ?Sized + SliceDst>(
+    len: usize,
+) -> Result<ptr::NonNull<S>, TryAllocError> {
+    try_alloc_slice_dst_in(|it| it, len)
+}
+
+/// Allocate a slice-based DST with the [global allocator][`alloc()`] within some container,
+/// returning an error instead of panicking or aborting on failure.
+///
+/// See [`alloc_slice_dst_in`] for details.
+pub fn try_alloc_slice_dst_in<S: ?Sized + SliceDst, F>(
+    container: F,
+    len: usize,
+) -> Result<ptr::NonNull<S>, TryAllocError>
+where
+    F: FnOnce(Layout) -> Layout,
+{
+    let layout = container(S::try_layout_for(len).map_err(TryAllocError::Layout)?);
     unsafe {
         let ptr = if layout.size() == 0 {
             // Do not allocate in the ZST case! CAD97/pointer-utils#23
@@ -224,12 +319,31 @@ where
         } else {
             ptr::NonNull::new(alloc(layout) as *mut ())
         }
-        .unwrap_or_else(|| handle_alloc_error(layout));
+        .ok_or(TryAllocError::Alloc(layout))?;
         let ptr = ptr::NonNull::new_unchecked(ptr::slice_from_raw_parts_mut(ptr.as_ptr(), len));
-        S::retype(ptr)
+        Ok(S::retype(ptr))
     }
 }
 
+/// Allocate a slice-based DST with the given [`Allocator`].
+///
+/// The returned pointer is owned and completely uninitialized;
+/// you are required to initialize it correctly.
+/// It should be deallocated with the same allocator, using `S::layout_for(len)`,
+/// or turned into a `Box<S, A>` with [`Box::from_raw_in`].
+///
+/// If the type to be allocated has zero size,
+/// then an arbitrary aligned dangling nonnull pointer is returned
+/// and the allocator is not used.
+#[cfg(feature = "allocator_api")]
+pub fn alloc_slice_dst_in_alloc<S: ?Sized + SliceDst, A: Allocator>(
+    len: usize,
+    alloc: &A,
+) -> ptr::NonNull<S> {
+    let raw = RawBox::<S, &A>::try_new_in(len, alloc).unwrap_or_else(|err| err.handle());
+    ManuallyDrop::new(raw).0
+}
+
 /// Types that can allocate a custom slice DST within them.
 ///
 /// # Implementation note
@@ -322,63 +436,177 @@ pub unsafe trait TryAllocSliceDst<S: ?Sized + SliceDst>: AllocSliceDst<S> + Size
         I: FnOnce(ptr::NonNull<S>) -> Result<(), E>;
 }
 
+/// An owned, uninitialized allocation for a `Box<S>`, deallocated on drop.
+#[cfg(not(feature = "allocator_api"))]
+pub(crate) struct RawBox<S: ?Sized + SliceDst>(pub(crate) ptr::NonNull<S>, Layout);
+
+#[cfg(not(feature = "allocator_api"))]
+impl<S: ?Sized + SliceDst> RawBox<S> {
+    pub(crate) fn try_new(len: usize) -> Result<Self, TryAllocError> {
+        let layout = S::try_layout_for(len).map_err(TryAllocError::Layout)?;
+        Ok(RawBox(try_alloc_slice_dst(len)?, layout))
+    }
+
+    /// # Safety
+    ///
+    /// The allocation must be fully initialized.
+    pub(crate) unsafe fn finalize(self) -> Box<S> {
+        let this = ManuallyDrop::new(self);
+        Box::from_raw(this.0.as_ptr())
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<S: ?Sized + SliceDst> Drop for RawBox<S> {
+    fn drop(&mut self) {
+        if self.1.size() != 0 {
+            unsafe { dealloc(self.0.as_ptr().cast(), self.1) }
+        }
+    }
+}
+
+/// An owned, uninitialized allocation for a `Box<S, A>`, deallocated on drop.
+#[cfg(feature = "allocator_api")]
+pub(crate) struct RawBox<S: ?Sized + SliceDst, A: Allocator = Global>(
+    pub(crate) ptr::NonNull<S>,
+    Layout,
+    ManuallyDrop<A>,
+);
+
+#[cfg(feature = "allocator_api")]
+impl<S: ?Sized + SliceDst> RawBox<S> {
+    pub(crate) fn try_new(len: usize) -> Result<Self, TryAllocError> {
+        RawBox::try_new_in(len, Global)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<S: ?Sized + SliceDst, A: Allocator> RawBox<S, A> {
+    pub(crate) fn try_new_in(len: usize, alloc: A) -> Result<Self, TryAllocError> {
+        let layout = S::try_layout_for(len).map_err(TryAllocError::Layout)?;
+        let ptr = if layout.size() == 0 {
+            // Do not allocate in the ZST case! CAD97/pointer-utils#23
+            unsafe { ptr::NonNull::new_unchecked(polyfill::ptr_dangling_at(layout.align())) }
+        } else {
+            alloc
+                .allocate(layout)
+                .map_err(|_| TryAllocError::Alloc(layout))?
+                .cast::<()>()
+        };
+        let ptr = S::retype(ptr::NonNull::slice_from_raw_parts(ptr, len));
+        Ok(RawBox(ptr, layout, ManuallyDrop::new(alloc)))
+    }
+
+    /// # Safety
+    ///
+    /// The allocation must be fully initialized.
+    pub(crate) unsafe fn finalize(self) -> Box<S, A> {
+        let mut this = ManuallyDrop::new(self);
+        Box::from_raw_in(this.0.as_ptr(), ManuallyDrop::take(&mut this.2))
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<S: ?Sized + SliceDst, A: Allocator> Drop for RawBox<S, A> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.1.size() != 0 {
+                self.2.deallocate(self.0.cast(), self.1);
+            }
+            ManuallyDrop::drop(&mut self.2);
+        }
+    }
+}
+
 // SAFETY: Box is guaranteed to be allocatable by GlobalAlloc.
+#[cfg(not(feature = "allocator_api"))]
 impl_alloc_by_try_alloc!(Box);
+#[cfg(not(feature = "allocator_api"))]
 unsafe impl<S: ?Sized + SliceDst> TryAllocSliceDst<S> for Box<S> {
     unsafe fn try_new_slice_dst<I, E>(len: usize, init: I) -> Result<Self, E>
     where
         I: FnOnce(ptr::NonNull<S>) -> Result<(), E>,
     {
-        struct RawBox<S: ?Sized + SliceDst>(ptr::NonNull<S>, Layout);
-
-        impl<S: ?Sized + SliceDst> RawBox<S> {
-            unsafe fn new(len: usize) -> Self {
-                let layout = S::layout_for(len);
-                RawBox(alloc_slice_dst(len), layout)
-            }
-
-            unsafe fn finalize(self) -> Box<S> {
-                let this = ManuallyDrop::new(self);
-                Box::from_raw(this.0.as_ptr())
-            }
-        }
+        let ptr = RawBox::try_new(len).unwrap_or_else(|err| err.handle());
+        init(ptr.0)?;
+        Ok(ptr.finalize())
+    }
+}
 
-        impl<S: ?Sized + SliceDst> Drop for RawBox<S> {
-            fn drop(&mut self) {
-                unsafe {
-                    dealloc(self.0.as_ptr().cast(), self.1);
-                }
-            }
+// SAFETY: Box<S, A> is guaranteed to be allocatable by A.
+#[cfg(feature = "allocator_api")]
+unsafe impl<S: ?Sized + SliceDst, A: Allocator + Default> AllocSliceDst<S> for Box<S, A> {
+    unsafe fn new_slice_dst<I>(len: usize, init: I) -> Self
+    where
+        I: FnOnce(ptr::NonNull<S>),
+    {
+        enum Void {}
+        #[allow(clippy::unit_arg)]
+        let init = |ptr| Ok::<(), Void>(init(ptr));
+        match Self::try_new_slice_dst(len, init) {
+            Ok(a) => a,
+            Err(void) => match void {},
         }
-
-        let ptr = RawBox::new(len);
+    }
+}
+#[cfg(feature = "allocator_api")]
+unsafe impl<S: ?Sized + SliceDst, A: Allocator + Default> TryAllocSliceDst<S> for Box<S, A> {
+    unsafe fn try_new_slice_dst<I, E>(len: usize, init: I) -> Result<Self, E>
+    where
+        I: FnOnce(ptr::NonNull<S>) -> Result<(), E>,
+    {
+        let ptr = RawBox::try_new_in(len, A::default()).unwrap_or_else(|err| err.handle());
         init(ptr.0)?;
         Ok(ptr.finalize())
     }
 }
 
-// SAFETY: just delegates to `Box`'s implementation (for now?)
+// SAFETY: initializes in place in an `Rc` allocation (see `in_place`)
 impl_alloc_by_try_alloc!(Rc);
 unsafe impl<S: ?Sized + SliceDst> TryAllocSliceDst<S> for Rc<S> {
     unsafe fn try_new_slice_dst<I, E>(len: usize, init: I) -> Result<Self, E>
     where
         I: FnOnce(ptr::NonNull<S>) -> Result<(), E>,
     {
-        Box::try_new_slice_dst(len, init).map(Into::into)
+        in_place::try_new_rc(len, init)
     }
 }
 
-// SAFETY: just delegates to `Box`'s implementation (for now?)
+// SAFETY: initializes in place in an `Arc` allocation (see `in_place`)
 impl_alloc_by_try_alloc!(Arc);
 unsafe impl<S: ?Sized + SliceDst> TryAllocSliceDst<S> for Arc<S> {
     unsafe fn try_new_slice_dst<I, E>(len: usize, init: I) -> Result<Self, E>
     where
         I: FnOnce(ptr::NonNull<S>) -> Result<(), E>,
     {
-        Box::try_new_slice_dst(len, init).map(Into::into)
+        in_place::try_new_arc(len, init)
+    }
+}
+
+// NB: a `Pin<Box<S>>` is initialized in its final location, so `init` may rely on its address.
+//     `Pin<Rc<S>>` and `Pin<Arc<S>>` are deliberately not supported, as `Rc<S>` and `Arc<S>`
+//     fall back to initializing in a `Box` and moving when they can't be initialized in place
+//     (see `in_place`), which would move the value after `init` has observed its address.
+// SAFETY: the allocation is fresh, so nothing else can observe it unpinned.
+unsafe impl<S: ?Sized + SliceDst> AllocSliceDst<S> for Pin<Box<S>> {
+    unsafe fn new_slice_dst<I>(len: usize, init: I) -> Self
+    where
+        I: FnOnce(ptr::NonNull<S>),
+    {
+        Pin::new_unchecked(Box::new_slice_dst(len, init))
+    }
+}
+// SAFETY: the allocation is fresh, so nothing else can observe it unpinned.
+unsafe impl<S: ?Sized + SliceDst> TryAllocSliceDst<S> for Pin<Box<S>> {
+    unsafe fn try_new_slice_dst<I, E>(len: usize, init: I) -> Result<Self, E>
+    where
+        I: FnOnce(ptr::NonNull<S>) -> Result<(), E>,
+    {
+        Box::try_new_slice_dst(len, init).map(|it| Pin::new_unchecked(it))
     }
 }
 
+mod in_place;
 pub(crate) mod polyfill;
 mod provided_types;
 
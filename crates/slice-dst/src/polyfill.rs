@@ -28,6 +28,7 @@ fn layout_padding_needed_for(this: &Layout, align: usize) -> usize {
     len_rounded_up.wrapping_sub(len)
 }
 
+#[allow(clippy::incompatible_msrv)] // gated on has_strict_provenance
 pub(crate) fn ptr_dangling_at<T>(addr: usize) -> *mut T {
     #[cfg(not(has_strict_provenance))]
     {
@@ -20,6 +20,10 @@ unsafe impl<Header, Item> SliceDst for SliceWithHeader<Header, Item> {
         Self::layout(len).0
     }
 
+    fn try_layout_for(len: usize) -> Result<Layout, LayoutError> {
+        Self::try_layout(len).map(|(layout, _)| layout)
+    }
+
     fn retype(ptr: ptr::NonNull<[()]>) -> ptr::NonNull<Self> {
         unsafe { ptr::NonNull::new_unchecked(ptr.as_ptr() as *mut _) }
     }
@@ -27,10 +31,14 @@ unsafe impl<Header, Item> SliceDst for SliceWithHeader<Header, Item> {
 
 impl<Header, Item> SliceWithHeader<Header, Item> {
     fn layout(len: usize) -> (Layout, [usize; 3]) {
+        Self::try_layout(len).unwrap()
+    }
+
+    fn try_layout(len: usize) -> Result<(Layout, [usize; 3]), LayoutError> {
         let length_layout = Layout::new::<usize>();
         let header_layout = Layout::new::<Header>();
-        let slice_layout = Layout::array::<Item>(len).unwrap();
-        polyfill::repr_c_3([length_layout, header_layout, slice_layout]).unwrap()
+        let slice_layout = Layout::array::<Item>(len)?;
+        polyfill::repr_c_3([length_layout, header_layout, slice_layout])
     }
 
     #[allow(clippy::new_ret_no_self)]
@@ -48,88 +56,68 @@ impl<Header, Item> SliceWithHeader<Header, Item> {
         let items = items.into_iter();
         let len = items.len();
 
-        struct InProgress<Header, Item> {
-            raw: ptr::NonNull<SliceWithHeader<Header, Item>>,
-            written: usize,
-            layout: Layout,
-            length_offset: usize,
-            header_offset: usize,
-            slice_offset: usize,
-        }
+        unsafe { A::new_slice_dst(len, InProgress::init(len, header, items)) }
+    }
 
-        impl<Header, Item> Drop for InProgress<Header, Item> {
-            fn drop(&mut self) {
-                unsafe {
-                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
-                        self.raw().add(self.slice_offset).cast::<Item>(),
-                        self.written,
-                    ));
-                }
-            }
+    /// Create a new slice/header DST in a [`Box`],
+    /// returning an error instead of panicking or aborting if allocation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items iterator incorrectly reports its length.
+    pub fn try_new<I>(header: Header, items: I) -> Result<Box<Self>, TryAllocError>
+    where
+        I: IntoIterator<Item = Item>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let items = items.into_iter();
+        let len = items.len();
+        let raw = RawBox::<Self>::try_new(len)?;
+        unsafe {
+            InProgress::init(len, header, items)(raw.0);
+            Ok(raw.finalize())
         }
+    }
 
-        impl<Header, Item> InProgress<Header, Item> {
-            fn init(
-                len: usize,
-                header: Header,
-                mut items: impl ExactSizeIterator<Item = Item>,
-            ) -> impl FnOnce(ptr::NonNull<SliceWithHeader<Header, Item>>) {
-                move |ptr| {
-                    let mut this = Self::new(len, ptr);
-
-                    unsafe {
-                        for _ in 0..len {
-                            let item = items
-                                .next()
-                                .expect("ExactSizeIterator over-reported length");
-                            this.push(item);
-                        }
-
-                        assert!(
-                            items.next().is_none(),
-                            "ExactSizeIterator under-reported length"
-                        );
-
-                        this.finish(len, header)
-                    }
-                }
-            }
-
-            fn raw(&self) -> *mut u8 {
-                self.raw.as_ptr().cast()
-            }
-
-            fn new(len: usize, raw: ptr::NonNull<SliceWithHeader<Header, Item>>) -> Self {
-                let (layout, [length_offset, header_offset, slice_offset]) =
-                    SliceWithHeader::<Header, Item>::layout(len);
-                InProgress {
-                    raw,
-                    written: 0,
-                    layout,
-                    length_offset,
-                    header_offset,
-                    slice_offset,
-                }
-            }
-
-            unsafe fn push(&mut self, item: Item) {
-                self.raw()
-                    .add(self.slice_offset)
-                    .cast::<Item>()
-                    .add(self.written)
-                    .write(item);
-                self.written += 1;
-            }
+    /// Create a new slice/header DST in a [`Box`] in the given allocator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items iterator incorrectly reports its length.
+    #[cfg(feature = "allocator_api")]
+    pub fn new_in<I, A>(header: Header, items: I, alloc: A) -> Box<Self, A>
+    where
+        I: IntoIterator<Item = Item>,
+        I::IntoIter: ExactSizeIterator,
+        A: Allocator,
+    {
+        Self::try_new_in(header, items, alloc).unwrap_or_else(|err| err.handle())
+    }
 
-            unsafe fn finish(self, len: usize, header: Header) {
-                let this = ManuallyDrop::new(self);
-                ptr::write(this.raw().add(this.length_offset).cast(), len);
-                ptr::write(this.raw().add(this.header_offset).cast(), header);
-                debug_assert_eq!(this.layout, Layout::for_value(this.raw.as_ref()))
-            }
+    /// Create a new slice/header DST in a [`Box`] in the given allocator,
+    /// returning an error instead of panicking or aborting if allocation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items iterator incorrectly reports its length.
+    #[cfg(feature = "allocator_api")]
+    pub fn try_new_in<I, A>(
+        header: Header,
+        items: I,
+        alloc: A,
+    ) -> Result<Box<Self, A>, TryAllocError>
+    where
+        I: IntoIterator<Item = Item>,
+        I::IntoIter: ExactSizeIterator,
+        A: Allocator,
+    {
+        let items = items.into_iter();
+        let len = items.len();
+        let raw = RawBox::<Self, A>::try_new_in(len, alloc)?;
+        unsafe {
+            InProgress::init(len, header, items)(raw.0);
+            Ok(raw.finalize())
         }
-
-        unsafe { A::new_slice_dst(len, InProgress::init(len, header, items)) }
     }
 
     #[allow(clippy::new_ret_no_self)]
@@ -153,6 +141,87 @@ impl<Header, Item> SliceWithHeader<Header, Item> {
     }
 }
 
+struct InProgress<Header, Item> {
+    raw: ptr::NonNull<SliceWithHeader<Header, Item>>,
+    written: usize,
+    layout: Layout,
+    length_offset: usize,
+    header_offset: usize,
+    slice_offset: usize,
+}
+
+impl<Header, Item> Drop for InProgress<Header, Item> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.raw().add(self.slice_offset).cast::<Item>(),
+                self.written,
+            ));
+        }
+    }
+}
+
+impl<Header, Item> InProgress<Header, Item> {
+    fn init(
+        len: usize,
+        header: Header,
+        mut items: impl ExactSizeIterator<Item = Item>,
+    ) -> impl FnOnce(ptr::NonNull<SliceWithHeader<Header, Item>>) {
+        move |ptr| {
+            let mut this = Self::new(len, ptr);
+
+            unsafe {
+                for _ in 0..len {
+                    let item = items
+                        .next()
+                        .expect("ExactSizeIterator over-reported length");
+                    this.push(item);
+                }
+
+                assert!(
+                    items.next().is_none(),
+                    "ExactSizeIterator under-reported length"
+                );
+
+                this.finish(len, header)
+            }
+        }
+    }
+
+    fn raw(&self) -> *mut u8 {
+        self.raw.as_ptr().cast()
+    }
+
+    fn new(len: usize, raw: ptr::NonNull<SliceWithHeader<Header, Item>>) -> Self {
+        let (layout, [length_offset, header_offset, slice_offset]) =
+            SliceWithHeader::<Header, Item>::layout(len);
+        InProgress {
+            raw,
+            written: 0,
+            layout,
+            length_offset,
+            header_offset,
+            slice_offset,
+        }
+    }
+
+    unsafe fn push(&mut self, item: Item) {
+        self.raw()
+            .add(self.slice_offset)
+            .cast::<Item>()
+            .add(self.written)
+            .write(item);
+        self.written += 1;
+    }
+
+    unsafe fn finish(self, len: usize, header: Header) {
+        let this = ManuallyDrop::new(self);
+        ptr::write(this.raw().add(this.length_offset).cast(), len);
+        ptr::write(this.raw().add(this.header_offset).cast(), header);
+        debug_assert_eq!(this.layout, Layout::for_value(this.raw.as_ref()))
+    }
+}
+
 impl<Header, Item> Clone for Box<SliceWithHeader<Header, Item>>
 where
     Header: Clone,
@@ -195,6 +264,10 @@ unsafe impl<Header> SliceDst for StrWithHeader<Header> {
         Self::layout(len).0
     }
 
+    fn try_layout_for(len: usize) -> Result<Layout, LayoutError> {
+        Self::try_layout(len).map(|(layout, _)| layout)
+    }
+
     fn retype(ptr: ptr::NonNull<[()]>) -> ptr::NonNull<Self> {
         unsafe { ptr::NonNull::new_unchecked(ptr.as_ptr() as *mut _) }
     }
@@ -202,10 +275,14 @@ unsafe impl<Header> SliceDst for StrWithHeader<Header> {
 
 impl<Header> StrWithHeader<Header> {
     fn layout(len: usize) -> (Layout, [usize; 3]) {
+        Self::try_layout(len).unwrap()
+    }
+
+    fn try_layout(len: usize) -> Result<(Layout, [usize; 3]), LayoutError> {
         let length_layout = Layout::new::<usize>();
         let header_layout = Layout::new::<Header>();
-        let slice_layout = Layout::array::<u8>(len).unwrap();
-        polyfill::repr_c_3([length_layout, header_layout, slice_layout]).unwrap()
+        let slice_layout = Layout::array::<u8>(len)?;
+        polyfill::repr_c_3([length_layout, header_layout, slice_layout])
     }
 
     #[allow(clippy::new_ret_no_self)]
@@ -214,18 +291,52 @@ impl<Header> StrWithHeader<Header> {
     where
         A: AllocSliceDst<Self>,
     {
-        let len = s.len();
-        let (layout, [length_offset, header_offset, str_offset]) = Self::layout(len);
+        unsafe { A::new_slice_dst(s.len(), |ptr| Self::write(ptr, header, s)) }
+    }
+
+    /// Create a new str/header DST in a [`Box`],
+    /// returning an error instead of panicking or aborting if allocation fails.
+    pub fn try_new(header: Header, s: &str) -> Result<Box<Self>, TryAllocError> {
+        let raw = RawBox::<Self>::try_new(s.len())?;
         unsafe {
-            A::new_slice_dst(len, |ptr| {
-                let raw = ptr.as_ptr().cast::<u8>();
-                ptr::write(raw.add(length_offset).cast(), len);
-                ptr::write(raw.add(header_offset).cast(), header);
-                ptr::copy_nonoverlapping(s.as_bytes().as_ptr(), raw.add(str_offset).cast(), len);
-                debug_assert_eq!(Layout::for_value(ptr.as_ref()), layout);
-            })
+            Self::write(raw.0, header, s);
+            Ok(raw.finalize())
+        }
+    }
+
+    /// Create a new str/header DST in a [`Box`] in the given allocator.
+    #[cfg(feature = "allocator_api")]
+    pub fn new_in<A: Allocator>(header: Header, s: &str, alloc: A) -> Box<Self, A> {
+        Self::try_new_in(header, s, alloc).unwrap_or_else(|err| err.handle())
+    }
+
+    /// Create a new str/header DST in a [`Box`] in the given allocator,
+    /// returning an error instead of panicking or aborting if allocation fails.
+    #[cfg(feature = "allocator_api")]
+    pub fn try_new_in<A: Allocator>(
+        header: Header,
+        s: &str,
+        alloc: A,
+    ) -> Result<Box<Self, A>, TryAllocError> {
+        let raw = RawBox::<Self, A>::try_new_in(s.len(), alloc)?;
+        unsafe {
+            Self::write(raw.0, header, s);
+            Ok(raw.finalize())
         }
     }
+
+    /// # Safety
+    ///
+    /// `ptr` must be an allocation for a `StrWithHeader` of length `s.len()`.
+    unsafe fn write(ptr: ptr::NonNull<Self>, header: Header, s: &str) {
+        let len = s.len();
+        let (layout, [length_offset, header_offset, str_offset]) = Self::layout(len);
+        let raw = ptr.as_ptr().cast::<u8>();
+        ptr::write(raw.add(length_offset).cast(), len);
+        ptr::write(raw.add(header_offset).cast(), header);
+        ptr::copy_nonoverlapping(s.as_bytes().as_ptr(), raw.add(str_offset).cast(), len);
+        debug_assert_eq!(Layout::for_value(ptr.as_ref()), layout);
+    }
 }
 
 impl<Header> Clone for Box<StrWithHeader<Header>>
@@ -0,0 +1,47 @@
+#![feature(allocator_api)]
+
+use {
+    slice_dst::*,
+    std::alloc::{AllocError, Allocator, Global, Layout},
+    std::ptr::NonNull,
+};
+
+#[derive(Default)]
+struct Counting;
+
+static ALLOCATED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+unsafe impl Allocator for Counting {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        ALLOCATED.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        ALLOCATED.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+        Global.deallocate(ptr, layout)
+    }
+}
+
+#[test]
+fn boxed_in() {
+    let slice: Box<SliceWithHeader<u8, u32>, Counting> = SliceWithHeader::new(0, vec![1, 2, 3]);
+    assert_eq!(ALLOCATED.load(std::sync::atomic::Ordering::Relaxed), 1);
+    assert_eq!(slice.slice, [1, 2, 3]);
+    drop(slice);
+    assert_eq!(ALLOCATED.load(std::sync::atomic::Ordering::Relaxed), 0);
+
+    let zst: Box<[()], Counting> = unsafe { Box::new_slice_dst(3, drop) };
+    assert_eq!(ALLOCATED.load(std::sync::atomic::Ordering::Relaxed), 0);
+    assert_eq!(zst.len(), 3);
+    drop(zst);
+    assert_eq!(ALLOCATED.load(std::sync::atomic::Ordering::Relaxed), 0);
+
+    let alloc = Counting;
+    let slice = SliceWithHeader::new_in(0, vec![1, 2, 3], &alloc);
+    let s = StrWithHeader::try_new_in(0, "in", &alloc).unwrap();
+    assert_eq!(ALLOCATED.load(std::sync::atomic::Ordering::Relaxed), 2);
+    assert_eq!((&slice.slice, &s.str), (&[1, 2, 3][..], "in"));
+    drop((slice, s));
+    assert_eq!(ALLOCATED.load(std::sync::atomic::Ordering::Relaxed), 0);
+}
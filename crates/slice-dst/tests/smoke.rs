@@ -72,3 +72,59 @@ fn node() {
     assert_eq!(boxed.data(), 6);
     dbg!(boxed);
 }
+
+#[test]
+fn try_new() {
+    let slice = SliceWithHeader::try_new((), vec![0, 1, 2]).unwrap();
+    assert_eq!(slice.slice, [0, 1, 2]);
+    let s = StrWithHeader::try_new((), "hello").unwrap();
+    assert_eq!(&s.str, "hello");
+
+    let overflow = SliceWithHeader::<(), u64>::try_new((), (0..usize::MAX / 4).map(|_| 0));
+    assert!(matches!(overflow, Err(TryAllocError::Layout(_))));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn try_new_oom() {
+    let oom = SliceWithHeader::<(), u8>::try_new((), (0..isize::MAX as usize / 2).map(|_| 0));
+    assert!(matches!(oom, Err(TryAllocError::Alloc(_))));
+}
+
+#[test]
+fn rc_in_place() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(align(128))]
+    struct OverAligned(u8);
+
+    let slice: Arc<SliceWithHeader<u8, u64>> = SliceWithHeader::new(1, vec![2, 3]);
+    assert_eq!((slice.header, &slice.slice), (1, &[2, 3][..]));
+    let slice: std::rc::Rc<StrWithHeader<u16>> = StrWithHeader::new(1, "two");
+    assert_eq!((slice.header, &slice.str), (1, "two"));
+    let slice: Arc<SliceWithHeader<OverAligned, ()>> =
+        SliceWithHeader::new(OverAligned(1), vec![(); 3]);
+    assert_eq!((slice.header, slice.slice.len()), (OverAligned(1), 3));
+    let slice: Arc<[u8]> =
+        unsafe { Arc::new_slice_dst(3, |ptr| ptr.cast::<u8>().write_bytes(7, 3)) };
+    assert_eq!(*slice, [7, 7, 7]);
+}
+
+#[test]
+fn pinned() {
+    use std::{cell::Cell, pin::Pin, ptr};
+
+    let slice: Pin<Box<SliceWithHeader<u8, u32>>> = SliceWithHeader::new(0, vec![1, 2]);
+    assert_eq!(slice.slice, [1, 2]);
+    let slice: Pin<Box<StrWithHeader<u8>>> = StrWithHeader::new(0, "pinned");
+    assert_eq!(&slice.str, "pinned");
+
+    let addr = Cell::new(ptr::null_mut::<u8>());
+    let slice: Pin<Box<[u8]>> = unsafe {
+        AllocSliceDst::new_slice_dst(3, |ptr: ptr::NonNull<[u8]>| {
+            addr.set(ptr.cast().as_ptr());
+            ptr.cast::<u8>().write_bytes(7, 3);
+        })
+    };
+    assert_eq!(*slice, [7, 7, 7]);
+    assert_eq!(addr.get().cast_const(), slice.as_ptr());
+}